@@ -0,0 +1,61 @@
+// JSON round trips through the serde feature:
+// cargo run --example serde_json --features serde
+use ft_matrix::{
+    complex::ComplexNumber, matrix::Matrix, vector::Vector,
+};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let a =
+        Matrix::new(vec![1., 2., 3., 4., 5., 6.], 2, 3)?;
+    let json = serde_json::to_string(&a)?;
+    println!("{}", json);
+    println!("// {{\"rows\":2,\"cols\":3,\"elements\":[1.0,2.0,3.0,4.0,5.0,6.0]}}");
+    println!(
+        "{}",
+        serde_json::from_str::<Matrix<f64>>(&json)? == a
+    );
+    println!("// true");
+
+    let v = Vector::new(vec![0.5f32, -1.5])?;
+    let json = serde_json::to_string(&v)?;
+    println!(
+        "{} {}",
+        json,
+        serde_json::from_str::<Vector<f32>>(&json)?
+    );
+    println!("// [0.5,-1.5] [0.5,-1.5]");
+
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(1., -1.),
+            ComplexNumber::Infinity,
+        ],
+        2,
+        1,
+    )?;
+    let json = serde_json::to_string(&c)?;
+    println!("{}", json);
+    println!("// {{\"rows\":2,\"cols\":1,\"elements\":[{{\"Cartesian\":{{\"re\":1.0,\"im\":-1.0}}}},\"Infinity\"]}}");
+    println!(
+        "{}",
+        serde_json::from_str::<Matrix<ComplexNumber>>(
+            &json
+        )?
+    );
+    println!("// [[1 + -1i,Inf]]");
+
+    // a shape that doesn't match the data is rejected, even if it overflows
+    for json in [
+        r#"{"rows":2,"cols":2,"elements":[1.0,2.0,3.0]}"#,
+        r#"{"rows":4294967296,"cols":4294967296,"elements":[]}"#,
+    ] {
+        match serde_json::from_str::<Matrix<f64>>(json) {
+            Ok(x) => println!("{}", x),
+            Err(e) => println!("{}", e),
+        }
+    }
+    println!("// Build error: expected 4 entries got 3");
+    println!("// Build error: expected 18446744073709551615 entries got 0");
+    Ok(())
+}
@@ -1,7 +1,11 @@
-use crate::traits::{Conj, Norm, One, Tf64};
+use crate::traits::{Conj, Ff64, Norm, One, Tf64};
 
 // Define an enumeration `ComplexNumber` representing complex numbers in Cartesian form or representing infinity
 #[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum ComplexNumber {
     Cartesian { re: f64, im: f64 },
     Infinity,
@@ -34,6 +38,12 @@ impl Tf64 for ComplexNumber {
         self.re()
     }
 }
+// Implement the `Ff64` trait for `ComplexNumber` to embed a real number on the real axis
+impl Ff64 for ComplexNumber {
+    fn ff64(x: f64) -> ComplexNumber {
+        ComplexNumber::n(x, 0.0)
+    }
+}
 // Implement the `Norm` trait for `ComplexNumber` to compute the Euclidean norm of the complex number
 impl Norm for ComplexNumber {
     fn norm(&self) -> f64 {
@@ -67,7 +77,7 @@ impl ComplexNumber {
         }
     }
     pub fn n(re: f64, im: f64) -> ComplexNumber {
-        ComplexNumber::Cartesian { re: re, im: im }
+        ComplexNumber::Cartesian { re, im }
     }
 
     pub fn inv(&self) -> ComplexNumber {
@@ -183,6 +193,7 @@ use std::ops::Div;
 // Implement the `Div` trait for `ComplexNumber` to provide division functionality
 impl Div for ComplexNumber {
     type Output = ComplexNumber;
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: Self) -> ComplexNumber {
         self * other.inv()
     }
@@ -198,7 +209,12 @@ impl fmt::Display for ComplexNumber {
         match self {
             ComplexNumber::Infinity => write!(f, "Inf"),
             ComplexNumber::Cartesian { re, im } => {
-                write!(f, "{re} + {im}i")
+                match f.precision() {
+                    Some(p) => {
+                        write!(f, "{re:.p$} + {im:.p$}i")
+                    }
+                    None => write!(f, "{re} + {im}i"),
+                }
             }
         }
     }
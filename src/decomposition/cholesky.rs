@@ -0,0 +1,119 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, traits::Field,
+    vector::Vector,
+};
+
+// Store the lower triangular factor of A = L·Lᴴ for Hermitian positive-definite matrices
+#[derive(Clone)]
+pub struct CholeskyDecomposition<K>
+where
+    K: Field,
+{
+    l: Matrix<K>,
+}
+
+impl<K> CholeskyDecomposition<K>
+where
+    K: Field,
+{
+    // Factorize a matrix reading only its lower triangle
+    pub fn new(
+        a: &Matrix<K>,
+    ) -> Result<CholeskyDecomposition<K>, LinAlgError> {
+        let (n, m) = a.size();
+        if n != m {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Not a square matrix. Can't calculate Cholesky factor."
+                    .to_string(),
+            });
+        }
+        let mut l = Matrix::<K>::zero(n, n)?;
+        for j in 1..=n {
+            let mut d = a.el(j, j)?.tf64();
+            for k in 1..j {
+                let x = l.el(j, k)?.norm();
+                d -= x * x;
+            }
+            if d.is_nan() || d <= 0.0 {
+                return Err(
+                    LinAlgError::NotPositiveDefinite,
+                );
+            }
+            let d = d.sqrt();
+            l.set(j, j, K::ff64(d))?;
+            for i in (j + 1)..=n {
+                let mut acc = a.el(i, j)?;
+                for k in 1..j {
+                    acc = acc
+                        - l.el(i, k)? * l.el(j, k)?.conj();
+                }
+                l.set(i, j, acc * K::ff64(1.0 / d))?;
+            }
+        }
+        Ok(CholeskyDecomposition { l })
+    }
+
+    // Lower triangular factor
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    // Solve A·X = B with a forward and a backward substitution
+    pub fn solve_mat(
+        &self,
+        b: &Matrix<K>,
+    ) -> Result<Matrix<K>, LinAlgError> {
+        let (n, _) = self.l.size();
+        let (rows, cols) = b.size();
+        if rows != n {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Right hand side does not match the system"
+                    .to_string(),
+            });
+        }
+        let mut x = b.clone();
+        for col in 1..=cols {
+            // L·y = b
+            for i in 1..=n {
+                let mut acc = x.el(i, col)?;
+                for k in 1..i {
+                    acc = acc
+                        - self.l.el(i, k)?
+                            * x.el(k, col)?;
+                }
+                x.set(i, col, acc / self.l.el(i, i)?)?;
+            }
+            // Lᴴ·x = y
+            for i in (1..=n).rev() {
+                let mut acc = x.el(i, col)?;
+                for k in (i + 1)..=n {
+                    acc = acc
+                        - self.l.el(k, i)?.conj()
+                            * x.el(k, col)?;
+                }
+                x.set(i, col, acc / self.l.el(i, i)?)?;
+            }
+        }
+        Ok(x)
+    }
+
+    // Solve A·x = b for a single right hand side
+    pub fn solve(
+        &self,
+        b: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        Vector::new(self.solve_mat(b.matrix())?.elements)
+    }
+
+    // Natural logarithm of det(A), which is real and positive
+    pub fn log_determinant(
+        &self,
+    ) -> Result<f64, LinAlgError> {
+        let (n, _) = self.l.size();
+        let mut to_return = 0.0;
+        for i in 1..=n {
+            to_return += 2.0 * self.l.el(i, i)?.tf64().ln();
+        }
+        Ok(to_return)
+    }
+}
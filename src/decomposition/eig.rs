@@ -0,0 +1,369 @@
+use crate::{
+    complex::ComplexNumber,
+    decomposition::qr::{
+        householder_vector, reflect_cols, reflect_rows,
+    },
+    errors::LinAlgError,
+    matrix::Matrix,
+    traits::{Conj, Norm},
+    vector::Vector,
+};
+
+const MAX_ITERATIONS: usize = 30;
+
+// Eigenvalues of a general real matrix: Hessenberg reduction followed by
+// the Francis double shift QR iteration. Complex conjugate pairs are next
+// to each other, the one with positive imaginary part first.
+pub(crate) fn eigenvalues(
+    a: &Matrix<f64>,
+) -> Result<Vector<ComplexNumber>, LinAlgError> {
+    let (n, m) = a.size();
+    if n != m {
+        return Err(LinAlgError::OperationNonConforming {
+            operation: "Not a square matrix. Can't calculate eigenvalues."
+                .to_string(),
+        });
+    }
+    let mut h = hessenberg(a)?;
+    let (wr, wi) = hqr(&mut h.elements, n)?;
+    Vector::new(
+        wr.iter()
+            .zip(wi.iter())
+            .map(|(re, im)| ComplexNumber::n(*re, *im))
+            .collect(),
+    )
+}
+
+// Right eigenvectors by inverse iteration on A − λ·I in complex arithmetic.
+// Eigenvalues closer than the shift can't be told apart by the solves, so
+// each vector is kept orthogonal to the earlier ones of its cluster.
+pub(crate) fn eigenvectors(
+    a: &Matrix<f64>,
+    values: &Vector<ComplexNumber>,
+) -> Result<Matrix<ComplexNumber>, LinAlgError> {
+    let (n, _) = a.size();
+    let complex = Matrix::new(
+        a.elements
+            .iter()
+            .map(|x| ComplexNumber::n(*x, 0.0))
+            .collect(),
+        n,
+        n,
+    )?;
+    let mut anorm = 0.0;
+    for x in &a.elements {
+        anorm += x.norm();
+    }
+    // keep A − λ·I away from exact singularity
+    let shift = f64::max(anorm, 1.0) * 1e3 * f64::EPSILON;
+
+    let mut to_return =
+        Matrix::<ComplexNumber>::zero(n, n)?;
+    for j in 1..=n {
+        let mut cluster = Vec::new();
+        for k in 1..j {
+            if (values.el(k)? - values.el(j)?).norm()
+                <= shift
+            {
+                cluster.push(k);
+            }
+        }
+        let lambda =
+            values.el(j)? + ComplexNumber::n(shift, 0.0);
+        let lu = complex
+            .sub(&Matrix::identity(n)?.scl(lambda)?)?
+            .lu()?;
+        let mut x = start_vector(&to_return, &cluster, j)?;
+        for _ in 0..3 {
+            x = lu.solve(&x)?;
+            orthogonalize(&mut x, &to_return, &cluster)?;
+            let norm = x.norm();
+            if norm == 0.0 || norm.is_nan() {
+                return Err(LinAlgError::SinglarMatrix);
+            }
+            x = x.scl(ComplexNumber::n(1.0 / norm, 0.0))?;
+        }
+        for i in 1..=n {
+            to_return.set(i, j, x.el(i)?)?;
+        }
+    }
+    Ok(to_return)
+}
+
+// Remove from x its components along the given orthonormal columns of u
+fn orthogonalize(
+    x: &mut Vector<ComplexNumber>,
+    u: &Matrix<ComplexNumber>,
+    columns: &[usize],
+) -> Result<(), LinAlgError> {
+    let (n, _) = u.size();
+    for &k in columns {
+        let mut proj = ComplexNumber::n(0.0, 0.0);
+        for i in 1..=n {
+            proj = proj + u.el(i, k)?.conj() * x.el(i)?;
+        }
+        for i in 1..=n {
+            let xi = x.el(i)? - u.el(i, k)? * proj;
+            x.set(i, xi)?;
+        }
+    }
+    Ok(())
+}
+
+// The ones vector for a new eigenvalue. A repeated one needs a start with
+// some weight along the directions of its eigenspace not found yet, which a
+// structured vector like ones can miss, so it gets a fixed pseudo-random one.
+fn start_vector(
+    u: &Matrix<ComplexNumber>,
+    columns: &[usize],
+    seed: usize,
+) -> Result<Vector<ComplexNumber>, LinAlgError> {
+    let (n, _) = u.size();
+    if columns.is_empty() {
+        return Vector::ones(n);
+    }
+    let mut state = seed as u64;
+    let mut x = Vector::<ComplexNumber>::zero(n)?;
+    for i in 1..=n {
+        // 64-bit linear congruential generator, top bits in [-0.5, 0.5)
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let r = (state >> 11) as f64 / (1u64 << 53) as f64;
+        x.set(i, ComplexNumber::n(r - 0.5, 0.0))?;
+    }
+    orthogonalize(&mut x, u, columns)?;
+    Ok(x)
+}
+
+// Reduce to upper Hessenberg form with similarity Householder reflections
+fn hessenberg(
+    a: &Matrix<f64>,
+) -> Result<Matrix<f64>, LinAlgError> {
+    let (n, _) = a.size();
+    let mut h = a.clone();
+    for k in 1..n.saturating_sub(1) {
+        let (v, alpha) =
+            match householder_vector(&h, k + 1, k)? {
+                Some(hv) => hv,
+                None => continue,
+            };
+        reflect_rows(&mut h, &v, k + 1, k)?;
+        reflect_cols(&mut h, &v, k + 1)?;
+        h.set(k + 1, k, alpha)?;
+        for i in (k + 2)..=n {
+            h.set(i, k, 0.0)?;
+        }
+    }
+    Ok(h)
+}
+
+fn sign(a: f64, b: f64) -> f64 {
+    if b >= 0.0 {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+// Shifted QR on an upper Hessenberg matrix stored column-major,
+// returning the real and imaginary parts of the eigenvalues
+fn hqr(
+    h: &mut [f64],
+    n: usize,
+) -> Result<(Vec<f64>, Vec<f64>), LinAlgError> {
+    // 1-based access so the indices follow the textbook algorithm
+    let at = |i: isize, j: isize| {
+        (i - 1) as usize + (j - 1) as usize * n
+    };
+    let mut wr = vec![0.0; n + 1];
+    let mut wi = vec![0.0; n + 1];
+    let n = n as isize;
+
+    let mut anorm = 0.0;
+    for i in 1..=n {
+        for j in isize::max(i - 1, 1)..=n {
+            anorm += h[at(i, j)].abs();
+        }
+    }
+    let mut nn = n;
+    let mut t = 0.0;
+    let (mut p, mut q, mut r) = (0.0, 0.0, 0.0);
+    let (mut x, mut y, mut z, mut w);
+    while nn >= 1 {
+        let mut its = 0;
+        loop {
+            // Look for a single small subdiagonal element
+            let mut l = nn;
+            while l >= 2 {
+                let mut s = h[at(l - 1, l - 1)].abs()
+                    + h[at(l, l)].abs();
+                if s == 0.0 {
+                    s = anorm;
+                }
+                if h[at(l, l - 1)].abs() + s == s {
+                    h[at(l, l - 1)] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+            x = h[at(nn, nn)];
+            if l == nn {
+                // one root found
+                wr[nn as usize] = x + t;
+                wi[nn as usize] = 0.0;
+                nn -= 1;
+            } else {
+                y = h[at(nn - 1, nn - 1)];
+                w = h[at(nn, nn - 1)] * h[at(nn - 1, nn)];
+                if l == nn - 1 {
+                    // two roots found
+                    p = 0.5 * (y - x);
+                    q = p * p + w;
+                    z = q.abs().sqrt();
+                    x += t;
+                    let (i1, i2) =
+                        ((nn - 1) as usize, nn as usize);
+                    if q >= 0.0 {
+                        z = p + sign(z, p);
+                        wr[i1] = x + z;
+                        wr[i2] = x + z;
+                        if z != 0.0 {
+                            wr[i2] = x - w / z;
+                        }
+                        wi[i1] = 0.0;
+                        wi[i2] = 0.0;
+                    } else {
+                        wr[i1] = x + p;
+                        wr[i2] = x + p;
+                        // positive imaginary part first
+                        wi[i1] = z;
+                        wi[i2] = -z;
+                    }
+                    nn -= 2;
+                } else {
+                    if its == MAX_ITERATIONS {
+                        return Err(
+                            LinAlgError::NoConvergence {
+                                iterations: MAX_ITERATIONS,
+                            },
+                        );
+                    }
+                    if its == 10 || its == 20 {
+                        // exceptional shift
+                        t += x;
+                        for i in 1..=nn {
+                            h[at(i, i)] -= x;
+                        }
+                        let s = h[at(nn, nn - 1)].abs()
+                            + h[at(nn - 1, nn - 2)].abs();
+                        x = 0.75 * s;
+                        y = x;
+                        w = -0.4375 * s * s;
+                    }
+                    its += 1;
+                    // Look for two consecutive small subdiagonal elements
+                    let mut m = nn - 2;
+                    while m >= l {
+                        z = h[at(m, m)];
+                        r = x - z;
+                        let s = y - z;
+                        p = (r * s - w) / h[at(m + 1, m)]
+                            + h[at(m, m + 1)];
+                        q = h[at(m + 1, m + 1)] - z - r - s;
+                        r = h[at(m + 2, m + 1)];
+                        let s = p.abs() + q.abs() + r.abs();
+                        p /= s;
+                        q /= s;
+                        r /= s;
+                        if m == l {
+                            break;
+                        }
+                        let u = h[at(m, m - 1)].abs()
+                            * (q.abs() + r.abs());
+                        let v = p.abs()
+                            * (h[at(m - 1, m - 1)].abs()
+                                + z.abs()
+                                + h[at(m + 1, m + 1)]
+                                    .abs());
+                        if u + v == v {
+                            break;
+                        }
+                        m -= 1;
+                    }
+                    for i in (m + 2)..=nn {
+                        h[at(i, i - 2)] = 0.0;
+                        if i != m + 2 {
+                            h[at(i, i - 3)] = 0.0;
+                        }
+                    }
+                    // Double QR step on rows l..nn and columns m..nn
+                    for k in m..nn {
+                        if k != m {
+                            p = h[at(k, k - 1)];
+                            q = h[at(k + 1, k - 1)];
+                            r = 0.0;
+                            if k != nn - 1 {
+                                r = h[at(k + 2, k - 1)];
+                            }
+                            x = p.abs() + q.abs() + r.abs();
+                            if x != 0.0 {
+                                p /= x;
+                                q /= x;
+                                r /= x;
+                            }
+                        }
+                        let s = sign(
+                            (p * p + q * q + r * r).sqrt(),
+                            p,
+                        );
+                        if s == 0.0 {
+                            continue;
+                        }
+                        if k == m {
+                            if l != m {
+                                h[at(k, k - 1)] =
+                                    -h[at(k, k - 1)];
+                            }
+                        } else {
+                            h[at(k, k - 1)] = -s * x;
+                        }
+                        p += s;
+                        x = p / s;
+                        y = q / s;
+                        z = r / s;
+                        q /= p;
+                        r /= p;
+                        for j in k..=nn {
+                            p = h[at(k, j)]
+                                + q * h[at(k + 1, j)];
+                            if k != nn - 1 {
+                                p += r * h[at(k + 2, j)];
+                                h[at(k + 2, j)] -= p * z;
+                            }
+                            h[at(k + 1, j)] -= p * y;
+                            h[at(k, j)] -= p * x;
+                        }
+                        let mmin = isize::min(nn, k + 3);
+                        for i in l..=mmin {
+                            p = x * h[at(i, k)]
+                                + y * h[at(i, k + 1)];
+                            if k != nn - 1 {
+                                p += z * h[at(i, k + 2)];
+                                h[at(i, k + 2)] -= p * r;
+                            }
+                            h[at(i, k + 1)] -= p * q;
+                            h[at(i, k)] -= p;
+                        }
+                    }
+                }
+            }
+            if l >= nn - 1 {
+                break;
+            }
+        }
+    }
+    wr.remove(0);
+    wi.remove(0);
+    Ok((wr, wi))
+}
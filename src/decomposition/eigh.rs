@@ -0,0 +1,179 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, traits::Field,
+    vector::Vector,
+};
+
+const MAX_SWEEPS: usize = 100;
+
+// Store the eigenvalues (ascending) and orthonormal eigenvectors of a Hermitian matrix
+pub struct SymmetricEigen<K>
+where
+    K: Field,
+{
+    eigenvalues: Vector<f64>,
+    eigenvectors: Matrix<K>,
+}
+
+impl<K> SymmetricEigen<K>
+where
+    K: Field,
+{
+    // Diagonalize a Hermitian matrix with cyclic Jacobi rotations
+    pub fn new(
+        a: &Matrix<K>,
+    ) -> Result<SymmetricEigen<K>, LinAlgError> {
+        let (n, m) = a.size();
+        if n != m {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Not a square matrix. Can't calculate eigenvalues."
+                    .to_string(),
+            });
+        }
+        let mut scale = 0.0;
+        for x in &a.elements {
+            scale += x.norm() * x.norm();
+        }
+        let scale = scale.sqrt();
+        check_hermitian(a, scale)?;
+
+        let mut d = a.clone();
+        let mut v = Matrix::<K>::identity(n)?;
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut off = 0.0;
+            for q in 2..=n {
+                for p in 1..q {
+                    off += d.el(p, q)?.norm()
+                        * d.el(p, q)?.norm();
+                }
+            }
+            if off.sqrt() <= K::EPSILON * scale {
+                converged = true;
+                break;
+            }
+            for p in 1..n {
+                for q in (p + 1)..=n {
+                    rotate(&mut d, &mut v, p, q)?;
+                }
+            }
+        }
+        if !converged {
+            return Err(LinAlgError::NoConvergence {
+                iterations: MAX_SWEEPS,
+            });
+        }
+
+        // Sort the eigenpairs by ascending eigenvalue
+        let mut order: Vec<(f64, usize)> =
+            Vec::with_capacity(n);
+        for i in 1..=n {
+            order.push((d.el(i, i)?.tf64(), i));
+        }
+        order.sort_by(|x, y| x.0.total_cmp(&y.0));
+        let mut eigenvectors = Matrix::<K>::zero(n, n)?;
+        for (j, &(_, col)) in order.iter().enumerate() {
+            for i in 1..=n {
+                eigenvectors.set(
+                    i,
+                    j + 1,
+                    v.el(i, col)?,
+                )?;
+            }
+        }
+        Ok(SymmetricEigen {
+            eigenvalues: Vector::new(
+                order.iter().map(|x| x.0).collect(),
+            )?,
+            eigenvectors,
+        })
+    }
+
+    // Real eigenvalues sorted in ascending order
+    pub fn eigenvalues(&self) -> &Vector<f64> {
+        &self.eigenvalues
+    }
+
+    // Eigenvectors stored as the columns of a unitary matrix
+    pub fn eigenvectors(&self) -> &Matrix<K> {
+        &self.eigenvectors
+    }
+}
+
+// Make sure that A = Aᴴ up to round-off
+fn check_hermitian<K>(
+    a: &Matrix<K>,
+    scale: f64,
+) -> Result<(), LinAlgError>
+where
+    K: Field,
+{
+    let (n, _) = a.size();
+    let tol = 100.0 * K::EPSILON * scale;
+    for j in 1..=n {
+        for i in j..=n {
+            if (a.el(i, j)? - a.el(j, i)?.conj()).norm()
+                > tol
+            {
+                return Err(LinAlgError::NotHermitian);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Apply the rotation that zeroes d(p, q) as d = Jᴴ·d·J and accumulate v = v·J
+fn rotate<K>(
+    d: &mut Matrix<K>,
+    v: &mut Matrix<K>,
+    p: usize,
+    q: usize,
+) -> Result<(), LinAlgError>
+where
+    K: Field,
+{
+    let (n, _) = d.size();
+    let apq = d.el(p, q)?;
+    let g = apq.norm();
+    if g == 0.0 {
+        return Ok(());
+    }
+    let app = d.el(p, p)?.tf64();
+    let aqq = d.el(q, q)?.tf64();
+    let theta = (aqq - app) / (2.0 * g);
+    let t = if theta.abs() > 1e150 {
+        0.5 / theta
+    } else {
+        theta.signum()
+            / (theta.abs() + (theta * theta + 1.0).sqrt())
+    };
+    let c = 1.0 / (t * t + 1.0).sqrt();
+    let s = t * c;
+    // phase of the off diagonal entry, one for real matrices
+    let e = apq * K::ff64(1.0 / g);
+    let c = K::ff64(c);
+    let jpq = K::ff64(s) * e;
+    let jqp = K::default() - K::ff64(s) * e.conj();
+
+    for k in 1..=n {
+        let akp = d.el(k, p)?;
+        let akq = d.el(k, q)?;
+        d.set(k, p, akp * c + akq * jqp)?;
+        d.set(k, q, akp * jpq + akq * c)?;
+        let vkp = v.el(k, p)?;
+        let vkq = v.el(k, q)?;
+        v.set(k, p, vkp * c + vkq * jqp)?;
+        v.set(k, q, vkp * jpq + vkq * c)?;
+    }
+    for k in 1..=n {
+        let apk = d.el(p, k)?;
+        let aqk = d.el(q, k)?;
+        d.set(p, k, c * apk + jqp.conj() * aqk)?;
+        d.set(q, k, jpq.conj() * apk + c * aqk)?;
+    }
+    // the rotation zeroes these exactly and keeps the diagonal real
+    d.set(p, q, K::default())?;
+    d.set(q, p, K::default())?;
+    d.set(p, p, K::ff64(d.el(p, p)?.tf64()))?;
+    d.set(q, q, K::ff64(d.el(q, q)?.tf64()))?;
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use crate::{
+    decomposition::qr::{householder_vector, reflect_rows},
+    errors::LinAlgError,
+    matrix::Matrix,
+    traits::Field,
+    vector::Vector,
+};
+
+// Result of a least-squares fit min ‖A·x − b‖₂
+pub struct LstsqSolution<K>
+where
+    K: Field,
+{
+    pub solution: Vector<K>,
+    pub residual_norm: f64,
+    pub rank: usize,
+}
+
+// Solve the least-squares problem with a column pivoted Householder QR,
+// dropping the directions whose pivot is negligible
+pub fn lstsq<K>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+) -> Result<LstsqSolution<K>, LinAlgError>
+where
+    K: Field,
+{
+    let (m, n) = a.size();
+    if b.size() != m {
+        return Err(LinAlgError::OperationNonConforming {
+            operation:
+                "Right hand side does not match the system"
+                    .to_string(),
+        });
+    }
+    let mut r = a.clone();
+    let mut y = b.matrix().clone();
+    let mut perm: Vec<usize> = (1..=n).collect();
+    let mut steps = 0;
+
+    for k in 1..=usize::min(m, n) {
+        // bring the remaining column of largest norm to the front
+        let mut best = k;
+        let mut best_norm = 0.0;
+        for j in k..=n {
+            let mut col_norm = 0.0;
+            for i in k..=m {
+                let x = r.el(i, j)?.norm();
+                col_norm += x * x;
+            }
+            if col_norm > best_norm {
+                best = j;
+                best_norm = col_norm;
+            }
+        }
+        if best_norm == 0.0 {
+            break;
+        }
+        if best != k {
+            swap_columns(&mut r, k, best)?;
+            perm.swap(k - 1, best - 1);
+        }
+        let (v, alpha) = match householder_vector(&r, k, k)?
+        {
+            Some(h) => h,
+            None => break,
+        };
+        reflect_rows(&mut r, &v, k, k)?;
+        reflect_rows(&mut y, &v, k, 1)?;
+        r.set(k, k, alpha)?;
+        for i in (k + 1)..=m {
+            r.set(i, k, K::default())?;
+        }
+        steps = k;
+    }
+
+    // Effective rank: pivots above the usual round-off threshold
+    let mut rank = 0;
+    if steps > 0 {
+        let tol = usize::max(m, n) as f64
+            * K::EPSILON
+            * r.el(1, 1)?.norm();
+        while rank < steps
+            && r.el(rank + 1, rank + 1)?.norm() > tol
+        {
+            rank += 1;
+        }
+    }
+
+    // Back substitution on the leading rank x rank block
+    let mut z = vec![K::default(); n];
+    for i in (1..=rank).rev() {
+        let mut acc = y.el(i, 1)?;
+        for k in (i + 1)..=rank {
+            acc = acc - r.el(i, k)? * z[k - 1];
+        }
+        z[i - 1] = acc / r.el(i, i)?;
+    }
+    let mut solution = Vector::<K>::zero(n)?;
+    for (k, &col) in perm.iter().enumerate() {
+        solution.set(col, z[k])?;
+    }
+    let residual_norm =
+        a.mul_vec(&solution)?.sub(b)?.norm();
+    Ok(LstsqSolution {
+        solution,
+        residual_norm,
+        rank,
+    })
+}
+
+fn swap_columns<K>(
+    a: &mut Matrix<K>,
+    col1: usize,
+    col2: usize,
+) -> Result<(), LinAlgError>
+where
+    K: Field,
+{
+    let (m, _) = a.size();
+    for i in 1..=m {
+        let int = a.el(i, col1)?;
+        a.set(i, col1, a.el(i, col2)?)?;
+        a.set(i, col2, int)?;
+    }
+    Ok(())
+}
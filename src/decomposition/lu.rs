@@ -0,0 +1,231 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, parallel,
+    traits::Field, vector::Vector,
+};
+
+// Store the factors of P·A = L·U obtained by Gaussian elimination with partial pivoting
+#[derive(Clone)]
+pub struct LuDecomposition<K>
+where
+    K: Field,
+{
+    l: Matrix<K>,
+    u: Matrix<K>,
+    perm: Vec<usize>,
+    pivots: Vec<usize>,
+    swaps: usize,
+}
+
+impl<K> LuDecomposition<K>
+where
+    K: Field,
+{
+    // Factorize a matrix picking in each column the pivot of largest magnitude
+    pub fn new(
+        a: &Matrix<K>,
+    ) -> Result<LuDecomposition<K>, LinAlgError> {
+        let (n, m) = a.size();
+        let mut u = a.clone();
+        let mut l = Matrix::<K>::identity(n)?;
+        let mut perm: Vec<usize> = (1..=n).collect();
+        let mut pivots = Vec::new();
+        let mut swaps = 0;
+        let mut pivot_row = 0;
+
+        for col in 1..=m {
+            if pivot_row == n {
+                break;
+            }
+            // look for the largest candidate on or below the next pivot row
+            let mut best = pivot_row + 1;
+            let mut best_norm = u.el(best, col)?.norm();
+            for row in (pivot_row + 2)..=n {
+                let candidate = u.el(row, col)?.norm();
+                if candidate > best_norm {
+                    best = row;
+                    best_norm = candidate;
+                }
+            }
+            if best_norm == 0.0 {
+                // no pivot in this column
+                continue;
+            }
+            pivot_row += 1;
+            if best != pivot_row {
+                u.row_swapping(best, pivot_row)?;
+                // only the multipliers already computed move with the row
+                for k in 1..pivot_row {
+                    let int = l.el(best, k)?;
+                    l.set(best, k, l.el(pivot_row, k)?)?;
+                    l.set(pivot_row, k, int)?;
+                }
+                perm.swap(best - 1, pivot_row - 1);
+                swaps += 1;
+            }
+            // Eliminate everything below the pivot
+            let pivot = u.el(pivot_row, col)?;
+            let mut factors = Vec::new();
+            for row in (pivot_row + 1)..=n {
+                let factor = u.el(row, col)? / pivot;
+                if factor == K::default() {
+                    continue;
+                }
+                l.set(row, pivot_row, factor)?;
+                u.set(row, col, K::default())?;
+                factors
+                    .push((row - 1, K::default() - factor));
+            }
+            // the columns right of the pivot are updated independently
+            parallel::for_columns(
+                &mut u.elements[col * n..],
+                n,
+                factors.len() * (m - col),
+                |_, block| {
+                    for column in block.chunks_mut(n) {
+                        let top = column[pivot_row - 1];
+                        for &(row, factor) in &factors {
+                            column[row] =
+                                column[row] + factor * top;
+                        }
+                    }
+                },
+            );
+            pivots.push(col);
+        }
+
+        Ok(LuDecomposition {
+            l,
+            u,
+            perm,
+            pivots,
+            swaps,
+        })
+    }
+
+    // Unit lower triangular factor
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    // Upper triangular (row echelon) factor
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    // Row permutation: row i of P·A is row perm[i - 1] of A
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    // Build the permutation matrix P
+    pub fn p(&self) -> Result<Matrix<K>, LinAlgError> {
+        let n = self.perm.len();
+        let mut to_return = Matrix::<K>::zero(n, n)?;
+        for (i, &j) in self.perm.iter().enumerate() {
+            to_return.set(i + 1, j, K::one())?;
+        }
+        Ok(to_return)
+    }
+
+    // Columns of the original matrix holding a pivot
+    pub fn pivot_columns(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    // Rank of the factorized matrix
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    // Check that the factorized matrix is square
+    fn check_square(
+        &self,
+        operation: &str,
+    ) -> Result<usize, LinAlgError> {
+        let (n, m) = self.u.size();
+        if n != m {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: format!(
+                    "Not a square matrix. Can't calculate {}.",
+                    operation
+                ),
+            });
+        }
+        Ok(n)
+    }
+
+    // Determinant from the product of the pivots and the parity of P
+    pub fn determinant(&self) -> Result<K, LinAlgError> {
+        let n = self.check_square("determinant")?;
+        if self.rank() != n {
+            return Ok(K::default());
+        }
+        let mut det = K::one();
+        if self.swaps % 2 == 1 {
+            det = K::default() - det;
+        }
+        for i in 1..=n {
+            det = det * self.u.el(i, i)?;
+        }
+        Ok(det)
+    }
+
+    // Solve A·X = B column by column with forward and back substitution
+    pub fn solve_mat(
+        &self,
+        b: &Matrix<K>,
+    ) -> Result<Matrix<K>, LinAlgError> {
+        let n = self.check_square("solution")?;
+        let (rows, cols) = b.size();
+        if rows != n {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Right hand side does not match the system"
+                    .to_string(),
+            });
+        }
+        if self.rank() != n {
+            return Err(LinAlgError::SinglarMatrix);
+        }
+        let mut x = Matrix::<K>::zero(n, cols)?;
+        for col in 1..=cols {
+            // L·y = P·b
+            for i in 1..=n {
+                let mut acc =
+                    b.el(self.perm[i - 1], col)?;
+                for k in 1..i {
+                    acc = acc
+                        - self.l.el(i, k)?
+                            * x.el(k, col)?;
+                }
+                x.set(i, col, acc)?;
+            }
+            // U·x = y
+            for i in (1..=n).rev() {
+                let mut acc = x.el(i, col)?;
+                for k in (i + 1)..=n {
+                    acc = acc
+                        - self.u.el(i, k)?
+                            * x.el(k, col)?;
+                }
+                x.set(i, col, acc / self.u.el(i, i)?)?;
+            }
+        }
+        Ok(x)
+    }
+
+    // Solve A·x = b for a single right hand side
+    pub fn solve(
+        &self,
+        b: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        Vector::new(self.solve_mat(b.matrix())?.elements)
+    }
+
+    // Inverse of the factorized matrix
+    pub fn inverse(
+        &self,
+    ) -> Result<Matrix<K>, LinAlgError> {
+        let n = self.check_square("inverse")?;
+        self.solve_mat(&Matrix::<K>::identity(n)?)
+    }
+}
@@ -0,0 +1,16 @@
+// Matrix factorizations that can be computed once and reused
+mod cholesky;
+pub(crate) mod eig;
+mod eigh;
+mod lstsq;
+mod lu;
+pub(crate) mod qr;
+mod svd;
+
+pub use cholesky::CholeskyDecomposition;
+pub use eigh::SymmetricEigen;
+pub(crate) use lstsq::lstsq;
+pub use lstsq::LstsqSolution;
+pub use lu::LuDecomposition;
+pub use qr::QrDecomposition;
+pub use svd::SingularValueDecomposition;
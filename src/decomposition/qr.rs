@@ -0,0 +1,164 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, traits::Field,
+};
+
+// Store the factors of A = Q·R computed with Householder reflections
+#[derive(Clone)]
+pub struct QrDecomposition<K>
+where
+    K: Field,
+{
+    q: Matrix<K>,
+    r: Matrix<K>,
+}
+
+impl<K> QrDecomposition<K>
+where
+    K: Field,
+{
+    // Factorize an m x n matrix, keeping only min(m, n) columns of Q when `economy` is set
+    pub fn new(
+        a: &Matrix<K>,
+        economy: bool,
+    ) -> Result<QrDecomposition<K>, LinAlgError> {
+        let (m, n) = a.size();
+        let mut r = a.clone();
+        let mut q = Matrix::<K>::identity(m)?;
+        let steps = usize::min(m - 1, n);
+
+        for k in 1..=steps {
+            let (v, alpha) =
+                match householder_vector(&r, k, k)? {
+                    Some(h) => h,
+                    None => continue,
+                };
+            reflect_rows(&mut r, &v, k, k)?;
+            reflect_cols(&mut q, &v, k)?;
+            // the reflection maps the column onto alpha·e1
+            r.set(k, k, alpha)?;
+            for i in (k + 1)..=m {
+                r.set(i, k, K::default())?;
+            }
+        }
+
+        if economy && m > n {
+            q = Matrix::new(
+                q.elements[..m * n].to_vec(),
+                m,
+                n,
+            )?;
+            let mut thin = Matrix::<K>::zero(n, n)?;
+            for j in 1..=n {
+                for i in 1..=j {
+                    thin.set(i, j, r.el(i, j)?)?;
+                }
+            }
+            r = thin;
+        }
+        Ok(QrDecomposition { q, r })
+    }
+
+    // Orthogonal (unitary for complex matrices) factor
+    pub fn q(&self) -> &Matrix<K> {
+        &self.q
+    }
+
+    // Upper triangular factor
+    pub fn r(&self) -> &Matrix<K> {
+        &self.r
+    }
+}
+
+// Build the Householder vector that zeroes column `col` below row `row`,
+// returning it with the value left on the diagonal
+pub(crate) fn householder_vector<K>(
+    a: &Matrix<K>,
+    row: usize,
+    col: usize,
+) -> Result<Option<(Vec<K>, K)>, LinAlgError>
+where
+    K: Field,
+{
+    let (m, _) = a.size();
+    let mut v = Vec::with_capacity(m - row + 1);
+    let mut x_norm = 0.0;
+    for i in row..=m {
+        let x = a.el(i, col)?;
+        x_norm += x.norm() * x.norm();
+        v.push(x);
+    }
+    let x_norm = x_norm.sqrt();
+    if x_norm == 0.0 {
+        return Ok(None);
+    }
+    // pick the sign (phase for complex) that avoids cancellation
+    let x1 = v[0];
+    let phase = if x1.norm() == 0.0 {
+        K::one()
+    } else {
+        x1 * K::ff64(1.0 / x1.norm())
+    };
+    let alpha = K::default() - phase * K::ff64(x_norm);
+    v[0] = x1 - alpha;
+    Ok(Some((v, alpha)))
+}
+
+// Apply H = I - 2·v·vᴴ / (vᴴ·v) from the left to rows row..m of columns col..n
+pub(crate) fn reflect_rows<K>(
+    a: &mut Matrix<K>,
+    v: &[K],
+    row: usize,
+    col: usize,
+) -> Result<(), LinAlgError>
+where
+    K: Field,
+{
+    let (_, n) = a.size();
+    let tau = K::ff64(2.0 / squared_norm(v));
+    for j in col..=n {
+        let mut w = K::default();
+        for (i, vi) in v.iter().enumerate() {
+            w = w + vi.conj() * a.el(row + i, j)?;
+        }
+        let w = tau * w;
+        for (i, vi) in v.iter().enumerate() {
+            a.set(row + i, j, a.el(row + i, j)? - *vi * w)?;
+        }
+    }
+    Ok(())
+}
+
+// Apply H from the right to columns col..col+len(v) of every row
+pub(crate) fn reflect_cols<K>(
+    a: &mut Matrix<K>,
+    v: &[K],
+    col: usize,
+) -> Result<(), LinAlgError>
+where
+    K: Field,
+{
+    let (m, _) = a.size();
+    let tau = K::ff64(2.0 / squared_norm(v));
+    for i in 1..=m {
+        let mut w = K::default();
+        for (j, vj) in v.iter().enumerate() {
+            w = w + a.el(i, col + j)? * *vj;
+        }
+        let w = tau * w;
+        for (j, vj) in v.iter().enumerate() {
+            a.set(
+                i,
+                col + j,
+                a.el(i, col + j)? - w * vj.conj(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn squared_norm<K>(v: &[K]) -> f64
+where
+    K: Field,
+{
+    v.iter().map(|x| x.norm() * x.norm()).sum()
+}
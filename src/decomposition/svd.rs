@@ -0,0 +1,272 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, traits::Field,
+    vector::Vector,
+};
+
+const MAX_SWEEPS: usize = 100;
+
+// Store the thin factors of A = U·Σ·Vᴴ with singular values in descending order
+pub struct SingularValueDecomposition<K>
+where
+    K: Field,
+{
+    u: Matrix<K>,
+    sigma: Vector<f64>,
+    v_h: Matrix<K>,
+}
+
+impl<K> SingularValueDecomposition<K>
+where
+    K: Field,
+{
+    // Compute the decomposition with one-sided Jacobi rotations
+    pub fn new(
+        a: &Matrix<K>,
+    ) -> Result<SingularValueDecomposition<K>, LinAlgError>
+    {
+        let (m, n) = a.size();
+        if m * n == 0 {
+            return Err(LinAlgError::EmptyArgs);
+        }
+        if m < n {
+            // Aᴴ = U·Σ·Vᴴ gives A = V·Σ·Uᴴ
+            let t =
+                SingularValueDecomposition::new(&a.adj()?)?;
+            return Ok(SingularValueDecomposition {
+                u: t.v_h.adj()?,
+                sigma: t.sigma,
+                v_h: t.u.adj()?,
+            });
+        }
+
+        let mut u = a.clone();
+        let mut v = Matrix::<K>::identity(n)?;
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 1..n {
+                for q in (p + 1)..=n {
+                    rotated |=
+                        rotate(&mut u, &mut v, p, q)?;
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(LinAlgError::NoConvergence {
+                iterations: MAX_SWEEPS,
+            });
+        }
+
+        // The singular values are the norms of the rotated columns
+        let mut order: Vec<(f64, usize)> =
+            Vec::with_capacity(n);
+        for j in 1..=n {
+            let mut norm = 0.0;
+            for i in 1..=m {
+                norm +=
+                    u.el(i, j)?.norm() * u.el(i, j)?.norm();
+            }
+            order.push((norm.sqrt(), j));
+        }
+        order.sort_by(|x, y| y.0.total_cmp(&x.0));
+
+        let mut left = Matrix::<K>::zero(m, n)?;
+        let mut right = Matrix::<K>::zero(n, n)?;
+        for (k, &(sigma, j)) in order.iter().enumerate() {
+            for i in 1..=n {
+                right.set(i, k + 1, v.el(i, j)?)?;
+            }
+            if sigma == 0.0 {
+                complete_basis(&mut left, k + 1)?;
+                continue;
+            }
+            let scaling = K::ff64(1.0 / sigma);
+            for i in 1..=m {
+                left.set(i, k + 1, u.el(i, j)? * scaling)?;
+            }
+        }
+        Ok(SingularValueDecomposition {
+            u: left,
+            sigma: Vector::new(
+                order.iter().map(|x| x.0).collect(),
+            )?,
+            v_h: right.adj()?,
+        })
+    }
+
+    // Left singular vectors, m x min(m, n)
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    // Singular values in descending order
+    pub fn singular_values(&self) -> &Vector<f64> {
+        &self.sigma
+    }
+
+    // Conjugate transpose of the right singular vectors, min(m, n) x n
+    pub fn v_h(&self) -> &Matrix<K> {
+        &self.v_h
+    }
+
+    // Number of singular values above `tol`
+    pub fn numerical_rank(
+        &self,
+        tol: f64,
+    ) -> Result<usize, LinAlgError> {
+        let mut rank = 0;
+        for i in 1..=self.sigma.size() {
+            if self.sigma.el(i)? > tol {
+                rank += 1;
+            }
+        }
+        Ok(rank)
+    }
+
+    // Default cutoff below which singular values count as zero
+    pub fn default_tolerance(
+        &self,
+    ) -> Result<f64, LinAlgError> {
+        let (m, _) = self.u.size();
+        let (_, n) = self.v_h.size();
+        Ok(usize::max(m, n) as f64
+            * K::EPSILON
+            * self.sigma.el(1)?)
+    }
+
+    // Spectral norm: the largest singular value
+    pub fn norm_2(&self) -> Result<f64, LinAlgError> {
+        self.sigma.el(1)
+    }
+
+    // Ratio between the largest and smallest singular values
+    pub fn cond(&self) -> Result<f64, LinAlgError> {
+        let smallest = self.sigma.el(self.sigma.size())?;
+        if smallest == 0.0 {
+            return Ok(f64::INFINITY);
+        }
+        Ok(self.sigma.el(1)? / smallest)
+    }
+
+    // Moore-Penrose pseudo-inverse V·Σ⁺·Uᴴ
+    pub fn pinv(&self) -> Result<Matrix<K>, LinAlgError> {
+        let tol = self.default_tolerance()?;
+        let (k, n) = self.v_h.size();
+        let mut v_sigma = self.v_h.adj()?;
+        for j in 1..=k {
+            let sigma = self.sigma.el(j)?;
+            let scaling = if sigma > tol {
+                K::ff64(1.0 / sigma)
+            } else {
+                K::default()
+            };
+            for i in 1..=n {
+                v_sigma.set(
+                    i,
+                    j,
+                    v_sigma.el(i, j)? * scaling,
+                )?;
+            }
+        }
+        v_sigma.mlt(&self.u.adj()?)
+    }
+}
+
+// Rotate columns p and q of u (and v) until they are orthogonal,
+// returning whether a rotation was needed
+fn rotate<K>(
+    u: &mut Matrix<K>,
+    v: &mut Matrix<K>,
+    p: usize,
+    q: usize,
+) -> Result<bool, LinAlgError>
+where
+    K: Field,
+{
+    let (m, _) = u.size();
+    let (n, _) = v.size();
+    let mut alpha = 0.0;
+    let mut beta = 0.0;
+    let mut gamma = K::default();
+    for i in 1..=m {
+        let up = u.el(i, p)?;
+        let uq = u.el(i, q)?;
+        alpha += up.norm() * up.norm();
+        beta += uq.norm() * uq.norm();
+        gamma = gamma + up.conj() * uq;
+    }
+    let g = gamma.norm();
+    if g == 0.0 || g <= K::EPSILON * (alpha * beta).sqrt() {
+        return Ok(false);
+    }
+    let zeta = (beta - alpha) / (2.0 * g);
+    let t = if zeta.abs() > 1e150 {
+        0.5 / zeta
+    } else {
+        zeta.signum()
+            / (zeta.abs() + (zeta * zeta + 1.0).sqrt())
+    };
+    let c = 1.0 / (t * t + 1.0).sqrt();
+    let s = t * c;
+    // phase of the inner product, one for real matrices
+    let e = gamma * K::ff64(1.0 / g);
+    let c = K::ff64(c);
+    let jpq = K::ff64(s) * e;
+    let jqp = K::default() - K::ff64(s) * e.conj();
+    for i in 1..=m {
+        let up = u.el(i, p)?;
+        let uq = u.el(i, q)?;
+        u.set(i, p, up * c + uq * jqp)?;
+        u.set(i, q, up * jpq + uq * c)?;
+    }
+    for i in 1..=n {
+        let vp = v.el(i, p)?;
+        let vq = v.el(i, q)?;
+        v.set(i, p, vp * c + vq * jqp)?;
+        v.set(i, q, vp * jpq + vq * c)?;
+    }
+    Ok(true)
+}
+
+// Fill column `col` with a unit vector orthogonal to the previous columns
+fn complete_basis<K>(
+    u: &mut Matrix<K>,
+    col: usize,
+) -> Result<(), LinAlgError>
+where
+    K: Field,
+{
+    let (m, _) = u.size();
+    // keep the canonical vector that survives Gram-Schmidt best
+    let mut best = (0.0, vec![K::default(); m]);
+    for e in 1..=m {
+        let mut w = vec![K::default(); m];
+        w[e - 1] = K::one();
+        for k in 1..col {
+            let mut proj = K::default();
+            for (i, wi) in w.iter().enumerate() {
+                proj = proj + u.el(i + 1, k)?.conj() * *wi;
+            }
+            for (i, wi) in w.iter_mut().enumerate() {
+                *wi = *wi - u.el(i + 1, k)? * proj;
+            }
+        }
+        let norm: f64 = w
+            .iter()
+            .map(|x| x.norm() * x.norm())
+            .sum::<f64>()
+            .sqrt();
+        if norm > best.0 {
+            best = (norm, w);
+        }
+    }
+    let (norm, w) = best;
+    for (i, wi) in w.iter().enumerate() {
+        u.set(i + 1, col, *wi * K::ff64(1.0 / norm))?;
+    }
+    Ok(())
+}
@@ -8,6 +8,11 @@ pub enum LinAlgError {
         operation: String,
     },
     SinglarMatrix,
+    NotPositiveDefinite,
+    NotHermitian,
+    NoConvergence {
+        iterations: usize,
+    },
     BuildNonconforming {
         expected: usize,
         recieved: usize,
@@ -21,6 +26,10 @@ pub enum LinAlgError {
         recieved: (usize, usize),
     },
     EmptyArgs,
+    Parse {
+        line: usize,
+        message: String,
+    },
 }
 
 // Implement the Display trait for `LinAlgError` to enable custom error messages
@@ -33,6 +42,17 @@ impl fmt::Display for LinAlgError {
             LinAlgError::SinglarMatrix => {
                 write!(f, "Operation not defined for a Singular Matrix")
             }
+            LinAlgError::NotPositiveDefinite => {
+                write!(f, "Matrix is not positive definite")
+            }
+            LinAlgError::NotHermitian => {
+                write!(f, "Matrix is not Hermitian")
+            }
+            LinAlgError::NoConvergence { iterations } => write!(
+                f,
+                "Algorithm did not converge after {} iterations",
+                iterations
+            ),
             LinAlgError::BuildNonconforming { expected, recieved } => write!(
                 f,
                 "Build error: expected {} entries got {}",
@@ -47,6 +67,9 @@ impl fmt::Display for LinAlgError {
                 write!(f, "Trying to access {} in a {} element", recieved, size)
             }
             LinAlgError::EmptyArgs => write!(f, "Recieved an empty object"),
+            LinAlgError::Parse { line, message } => {
+                write!(f, "Parse error on line {}: {}", line, message)
+            }
         }
     }
 }
@@ -0,0 +1,312 @@
+use crate::{matrix::Matrix, traits::Field};
+use std::fmt;
+
+// How `Pretty` lays the matrix out
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Style {
+    // One bracketed row per line, columns aligned
+    Plain,
+    // A bmatrix environment
+    Latex,
+    // A table with the column numbers as header
+    Markdown,
+    // A literal like [1 2; 3 4], never elided
+    Octave,
+    // Same as Octave, with complex numbers written as 1+2im
+    Julia,
+}
+
+// Row by row view of a matrix for printing. Formatting flags apply to
+// every entry, e.g. `{:8.3}` or `{:<.2}`.
+pub struct Pretty<'a, K>
+where
+    K: Field,
+{
+    matrix: &'a Matrix<K>,
+    style: Style,
+    max_rows: usize,
+    max_cols: usize,
+}
+
+impl<K> Matrix<K>
+where
+    K: Field,
+{
+    // Print rows on separate lines, eliding the middle of large matrices
+    pub fn pretty(&self) -> Pretty<'_, K> {
+        Pretty {
+            matrix: self,
+            style: Style::Plain,
+            max_rows: 10,
+            max_cols: 10,
+        }
+    }
+}
+
+impl<'a, K> Pretty<'a, K>
+where
+    K: Field,
+{
+    pub fn style(self, style: Style) -> Pretty<'a, K> {
+        Pretty { style, ..self }
+    }
+
+    pub fn latex(self) -> Pretty<'a, K> {
+        self.style(Style::Latex)
+    }
+
+    pub fn markdown(self) -> Pretty<'a, K> {
+        self.style(Style::Markdown)
+    }
+
+    pub fn octave(self) -> Pretty<'a, K> {
+        self.style(Style::Octave)
+    }
+
+    pub fn julia(self) -> Pretty<'a, K> {
+        self.style(Style::Julia)
+    }
+
+    // Show at most this many rows and columns, half from each end;
+    // usize::MAX never elides
+    pub fn max_size(
+        self,
+        rows: usize,
+        cols: usize,
+    ) -> Pretty<'a, K> {
+        Pretty {
+            max_rows: rows.max(1),
+            max_cols: cols.max(1),
+            ..self
+        }
+    }
+}
+
+// Indices shown out of n, None standing for the elided middle
+fn shown(n: usize, max: usize) -> Vec<Option<usize>> {
+    if n <= max {
+        return (0..n).map(Some).collect();
+    }
+    let (head, tail) = (max.div_ceil(2), max / 2);
+    (0..head)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((n - tail..n).map(Some))
+        .collect()
+}
+
+// Octave and Julia read 1+-2i as 1-2i but not the spaces Display puts in
+fn literal(cell: &str, style: Style) -> String {
+    let cell = cell.replace(' ', "");
+    let cell = match cell.as_str() {
+        "inf" => "Inf".to_string(),
+        "-inf" => "-Inf".to_string(),
+        _ => cell,
+    };
+    match cell.strip_suffix('i') {
+        Some(number) if style == Style::Julia => {
+            format!("{}im", number)
+        }
+        _ => cell,
+    }
+}
+
+fn pad(
+    align: Option<fmt::Alignment>,
+    cell: &str,
+    width: usize,
+) -> String {
+    let fill = width.saturating_sub(cell.chars().count());
+    let (left, right) = match align {
+        Some(fmt::Alignment::Left) => (0, fill),
+        Some(fmt::Alignment::Center) => {
+            (fill / 2, fill - fill / 2)
+        }
+        _ => (fill, 0),
+    };
+    format!(
+        "{}{}{}",
+        " ".repeat(left),
+        cell,
+        " ".repeat(right)
+    )
+}
+
+impl<K> fmt::Display for Pretty<'_, K>
+where
+    K: Field,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let (n, m) = self.matrix.size();
+        let align = f.align();
+        if n == 0 || m == 0 {
+            return write!(f, "[]");
+        }
+        let literal_style = matches!(
+            self.style,
+            Style::Octave | Style::Julia
+        );
+        let (max_rows, max_cols) = if literal_style {
+            (usize::MAX, usize::MAX)
+        } else {
+            (self.max_rows, self.max_cols)
+        };
+        let (rows, cols) =
+            (shown(n, max_rows), shown(m, max_cols));
+        let (vertical, horizontal, diagonal) =
+            match self.style {
+                Style::Latex => {
+                    ("\\vdots", "\\cdots", "\\ddots")
+                }
+                _ => ("⋮", "…", "⋱"),
+            };
+
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|i| {
+                cols.iter()
+                    .map(|j| match (i, j) {
+                        (Some(i), Some(j)) => {
+                            let x = self.matrix.elements
+                                [i + j * n];
+                            let cell = match f.precision() {
+                                Some(p) => {
+                                    format!("{:.*}", p, x)
+                                }
+                                None => format!("{}", x),
+                            };
+                            if literal_style {
+                                literal(&cell, self.style)
+                            } else {
+                                cell
+                            }
+                        }
+                        (None, Some(_)) => {
+                            vertical.to_string()
+                        }
+                        (Some(_), None) => {
+                            horizontal.to_string()
+                        }
+                        (None, None) => {
+                            diagonal.to_string()
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = (0..cols.len())
+            .map(|c| {
+                cells
+                    .iter()
+                    .map(|row| row[c].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(f.width().unwrap_or(0))
+            })
+            .collect();
+        let aligned = |row: &Vec<String>| -> Vec<String> {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| {
+                    pad(align, cell, *width)
+                })
+                .collect()
+        };
+
+        match self.style {
+            Style::Plain => {
+                for (r, row) in cells.iter().enumerate() {
+                    if r > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(
+                        f,
+                        "[ {} ]",
+                        aligned(row).join("  ")
+                    )?;
+                }
+            }
+            Style::Latex => {
+                writeln!(f, "\\begin{{bmatrix}}")?;
+                for (r, row) in cells.iter().enumerate() {
+                    let end = if r + 1 < cells.len() {
+                        " \\\\"
+                    } else {
+                        ""
+                    };
+                    writeln!(
+                        f,
+                        "  {}{}",
+                        aligned(row).join(" & "),
+                        end
+                    )?;
+                }
+                write!(f, "\\end{{bmatrix}}")?;
+            }
+            Style::Markdown => {
+                let header: Vec<String> = cols
+                    .iter()
+                    .map(|j| match j {
+                        Some(j) => (j + 1).to_string(),
+                        None => horizontal.to_string(),
+                    })
+                    .collect();
+                // the header may be wider than every entry
+                let widths: Vec<usize> = widths
+                    .iter()
+                    .zip(&header)
+                    .map(|(w, h)| {
+                        (*w).max(h.chars().count()).max(3)
+                    })
+                    .collect();
+                let line = |row: &Vec<String>| -> String {
+                    let cells: Vec<String> = row
+                        .iter()
+                        .zip(&widths)
+                        .map(|(cell, width)| {
+                            pad(align, cell, *width)
+                        })
+                        .collect();
+                    format!("| {} |", cells.join(" | "))
+                };
+                writeln!(f, "{}", line(&header))?;
+                let rule: Vec<String> = widths
+                    .iter()
+                    .map(|w| match align {
+                        Some(fmt::Alignment::Left) => {
+                            format!(
+                                ":{}",
+                                "-".repeat(w - 1)
+                            )
+                        }
+                        Some(fmt::Alignment::Center) => {
+                            format!(
+                                ":{}:",
+                                "-".repeat(w - 2)
+                            )
+                        }
+                        _ => format!(
+                            "{}:",
+                            "-".repeat(w - 1)
+                        ),
+                    })
+                    .collect();
+                write!(f, "| {} |", rule.join(" | "))?;
+                for row in &cells {
+                    write!(f, "\n{}", line(row))?;
+                }
+            }
+            Style::Octave | Style::Julia => {
+                let rows: Vec<String> = cells
+                    .iter()
+                    .map(|row| row.join(" "))
+                    .collect();
+                write!(f, "[{}]", rows.join("; "))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+// Blocked and packed general matrix multiplication C = α·A·B + β·C
+use crate::{
+    errors::LinAlgError,
+    matrix::Matrix,
+    parallel,
+    traits::Field,
+    view::{AsMatrixView, MatrixView},
+};
+
+// Register tile computed by the micro kernel, matching `Kernels::tile_4x4`
+const MR: usize = 4;
+const NR: usize = 4;
+// Cache blocks: an MC x KC block of A and a KC x NC panel of B
+const MC: usize = 64;
+const KC: usize = 256;
+const NC: usize = 512;
+
+// Compute C = α·A·B + β·C in place. Every entry of A·B is accumulated
+// in increasing k order starting from zero, so with α = 1 and β = 0 the
+// result matches the textbook triple loop bit for bit.
+pub fn gemm<A, B, K>(
+    alpha: K,
+    a: &A,
+    b: &B,
+    beta: K,
+    c: &mut Matrix<K>,
+) -> Result<(), LinAlgError>
+where
+    A: AsMatrixView<K> + ?Sized,
+    B: AsMatrixView<K> + ?Sized,
+    K: Field,
+{
+    let a = a.view();
+    let b = b.view();
+    let (m, k) = a.size();
+    let (k2, n) = b.size();
+    if k != k2 || c.size() != (m, n) {
+        return Err(LinAlgError::OperationNonConforming {
+            operation:
+                "Sizes not conform to multiplication"
+                    .to_string(),
+        });
+    }
+
+    // β = 0 overwrites C, so NaNs already in it don't leak through
+    if beta == K::default() {
+        c.elements.fill(K::default());
+    } else if beta != K::one() {
+        for x in c.elements.iter_mut() {
+            *x = beta * *x;
+        }
+    }
+    if alpha == K::default() || m * n * k == 0 {
+        return Ok(());
+    }
+
+    parallel::for_columns(
+        &mut c.elements,
+        m,
+        m * n * k,
+        |first, block| {
+            multiply(alpha, &a, &b, first, block, m)
+        },
+    );
+    Ok(())
+}
+
+// Accumulate α·A·B into a block of consecutive columns of C, starting at
+// column `first`. The packed blocks of A and B live in the thread's scratch
+// buffer, so after the first product on a thread nothing is allocated.
+fn multiply<K>(
+    alpha: K,
+    a: &MatrixView<'_, K>,
+    b: &MatrixView<'_, K>,
+    first: usize,
+    c: &mut [K],
+    m: usize,
+) where
+    K: Field,
+{
+    let (_, k) = a.size();
+    let n = c.len() / m;
+    let mc = round_up(usize::min(m, MC), MR);
+    let kc = usize::min(k, KC);
+    let nc = round_up(usize::min(n, NC), NR);
+    K::with_scratch((mc + nc) * kc, |buffer| {
+        let (a_pack, b_pack) = buffer.split_at_mut(mc * kc);
+        pack_and_multiply(
+            alpha, a, b, first, c, m, a_pack, b_pack,
+        )
+    })
+}
+
+// The blocked loops of `multiply`, given its packing buffers
+#[allow(clippy::too_many_arguments)]
+fn pack_and_multiply<K>(
+    alpha: K,
+    a: &MatrixView<'_, K>,
+    b: &MatrixView<'_, K>,
+    first: usize,
+    c: &mut [K],
+    m: usize,
+    a_pack: &mut [K],
+    b_pack: &mut [K],
+) where
+    K: Field,
+{
+    let (_, k) = a.size();
+    let n = c.len() / m;
+
+    for jc in (0..n).step_by(NC) {
+        let nb = usize::min(NC, n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kb = usize::min(KC, k - pc);
+            pack_b(b, pc, first + jc, kb, nb, b_pack);
+            for ic in (0..m).step_by(MC) {
+                let mb = usize::min(MC, m - ic);
+                pack_a(a, alpha, ic, pc, mb, kb, a_pack);
+                for jr in (0..nb).step_by(NR) {
+                    for ir in (0..mb).step_by(MR) {
+                        micro_kernel(
+                            &a_pack
+                                [ir * kb..(ir + MR) * kb],
+                            &b_pack
+                                [jr * kb..(jr + NR) * kb],
+                            kb,
+                            c,
+                            m,
+                            (ic + ir, jc + jr),
+                            (
+                                usize::min(MR, mb - ir),
+                                usize::min(NR, nb - jr),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn round_up(x: usize, multiple: usize) -> usize {
+    x.div_ceil(multiple) * multiple
+}
+
+// Copy an mb x kb block of A into MR-row slivers, each stored
+// column by column, scaling by α on the way in
+fn pack_a<K>(
+    a: &MatrixView<'_, K>,
+    alpha: K,
+    ic: usize,
+    pc: usize,
+    mb: usize,
+    kb: usize,
+    pack: &mut [K],
+) where
+    K: Field,
+{
+    let mut index = 0;
+    for ir in (0..mb).step_by(MR) {
+        for p in 0..kb {
+            for i in ir..ir + MR {
+                pack[index] = if i < mb {
+                    let x = a.at(ic + i, pc + p);
+                    if alpha == K::one() {
+                        x
+                    } else {
+                        alpha * x
+                    }
+                } else {
+                    K::default()
+                };
+                index += 1;
+            }
+        }
+    }
+}
+
+// Copy a kb x nb panel of B into NR-column slivers, each stored row by row
+fn pack_b<K>(
+    b: &MatrixView<'_, K>,
+    pc: usize,
+    jc: usize,
+    kb: usize,
+    nb: usize,
+    pack: &mut [K],
+) where
+    K: Field,
+{
+    let mut index = 0;
+    for jr in (0..nb).step_by(NR) {
+        for p in 0..kb {
+            for j in jr..jr + NR {
+                pack[index] = if j < nb {
+                    b.at(pc + p, jc + j)
+                } else {
+                    K::default()
+                };
+                index += 1;
+            }
+        }
+    }
+}
+
+// Update an MR x NR tile of C from one sliver of A and one of B,
+// writing back only the `rows` x `cols` entries that exist
+fn micro_kernel<K>(
+    a: &[K],
+    b: &[K],
+    kb: usize,
+    c: &mut [K],
+    ldc: usize,
+    (i0, j0): (usize, usize),
+    (rows, cols): (usize, usize),
+) where
+    K: Field,
+{
+    let mut acc = [[K::default(); MR]; NR];
+    for (j, column) in acc.iter_mut().enumerate().take(cols)
+    {
+        let start = i0 + (j0 + j) * ldc;
+        column[..rows]
+            .copy_from_slice(&c[start..start + rows]);
+    }
+    K::tile_4x4(&a[..kb * MR], &b[..kb * NR], &mut acc);
+    for (j, column) in acc.iter().enumerate().take(cols) {
+        let start = i0 + (j0 + j) * ldc;
+        c[start..start + rows]
+            .copy_from_slice(&column[..rows]);
+    }
+}
@@ -0,0 +1,332 @@
+// In-place and `_into` arithmetic that reuses existing buffers
+use crate::{
+    errors::LinAlgError, gemm::gemm, matrix::Matrix,
+    parallel, traits::Field, vector::Vector,
+    view::AsMatrixView,
+};
+
+impl<K> Matrix<K>
+where
+    K: Field,
+{
+    // self ← a·x + self
+    fn update<M>(
+        &mut self,
+        a: K,
+        x: &M,
+        operation: &str,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        let x = x.view();
+        if x.size() != self.size() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: operation.to_string(),
+                },
+            );
+        }
+        let (n, m) = self.size();
+        let contiguous = x.as_slice();
+        parallel::for_columns(
+            &mut self.elements,
+            n,
+            n * m,
+            |first, block| match contiguous {
+                Some(x) => K::axpy(
+                    a,
+                    &x[first * n..first * n + block.len()],
+                    block,
+                ),
+                None => {
+                    for (j, column) in
+                        block.chunks_mut(n).enumerate()
+                    {
+                        for (i, y) in
+                            column.iter_mut().enumerate()
+                        {
+                            *y =
+                                *y + a * x.at(i, first + j);
+                        }
+                    }
+                }
+            },
+        );
+        Ok(())
+    }
+    // Add a matrix (or view) of the same dimensions in place
+    pub fn add_assign<M>(
+        &mut self,
+        other: &M,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.update(K::one(), other, "Matrix Addition")
+    }
+    // Subtract a matrix (or view) of the same dimensions in place
+    pub fn sub_assign<M>(
+        &mut self,
+        other: &M,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.update(
+            K::default() - K::one(),
+            other,
+            "Matrix Addition",
+        )
+    }
+    // Add a·x to the matrix in place
+    pub fn axpy<M>(
+        &mut self,
+        a: K,
+        x: &M,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.update(a, x, "axpy")
+    }
+    // Scale the matrix by a factor in place
+    pub fn scale_mut(
+        &mut self,
+        scaling: K,
+    ) -> Result<(), LinAlgError> {
+        let (n, m) = self.size();
+        parallel::for_columns(
+            &mut self.elements,
+            n,
+            n * m,
+            |_, block| {
+                for x in block.iter_mut() {
+                    *x = scaling * *x;
+                }
+            },
+        );
+        Ok(())
+    }
+    // Transpose without a second buffer of elements: swaps for square
+    // matrices and cycle following for rectangular ones
+    pub fn transpose_in_place(
+        &mut self,
+    ) -> Result<(), LinAlgError> {
+        let (n, m) = self.size();
+        if n == m {
+            for j in 0..n {
+                for i in (j + 1)..n {
+                    self.elements
+                        .swap(i + j * n, j + i * n);
+                }
+            }
+        } else if n > 1 && m > 1 {
+            // entry k = i + j·n moves to j + i·m; the first and last
+            // entries stay where they are. A bitset of moved entries,
+            // n·m/8 bytes, lets each cycle be followed exactly once.
+            let len = n * m;
+            let next = |k: usize| (k % n) * m + k / n;
+            let mut moved = vec![0u64; len.div_ceil(64)];
+            for start in 1..len - 1 {
+                if moved[start / 64] & (1 << (start % 64))
+                    != 0
+                {
+                    continue;
+                }
+                let mut k = start;
+                let mut carried = self.elements[start];
+                loop {
+                    k = next(k);
+                    moved[k / 64] |= 1 << (k % 64);
+                    std::mem::swap(
+                        &mut self.elements[k],
+                        &mut carried,
+                    );
+                    if k == start {
+                        break;
+                    }
+                }
+            }
+        }
+        let elements = std::mem::take(&mut self.elements);
+        *self = Matrix::new(elements, m, n)?;
+        Ok(())
+    }
+    // Write self + other into `out`, reusing its allocation
+    pub fn add_into<M>(
+        &self,
+        other: &M,
+        out: &mut Matrix<K>,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.view().zip_with_into(
+            other,
+            "Matrix Addition",
+            |x, y| x + y,
+            out,
+        )
+    }
+    // Write self - other into `out`, reusing its allocation
+    pub fn sub_into<M>(
+        &self,
+        other: &M,
+        out: &mut Matrix<K>,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.view().zip_with_into(
+            other,
+            "Matrix Addition",
+            |x, y| x - y,
+            out,
+        )
+    }
+    // Write scaling·self into `out`, reusing its allocation
+    pub fn scl_into(
+        &self,
+        scaling: K,
+        out: &mut Matrix<K>,
+    ) -> Result<(), LinAlgError> {
+        let (n, m) = self.size();
+        out.reset(n, m);
+        parallel::fill_with(
+            &mut out.elements,
+            n,
+            |i, j| scaling * self.elements[i + j * n],
+        );
+        Ok(())
+    }
+    // Write the transpose into `out`, reusing its allocation
+    pub fn tr_into(
+        &self,
+        out: &mut Matrix<K>,
+    ) -> Result<(), LinAlgError> {
+        let (n, m) = self.size();
+        out.reset(m, n);
+        parallel::fill_with(
+            &mut out.elements,
+            m,
+            |i, j| self.elements[j + i * n],
+        );
+        Ok(())
+    }
+    // Write self·other into `out`, reusing its allocation
+    pub fn mlt_into<M>(
+        &self,
+        other: &M,
+        out: &mut Matrix<K>,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        let (n, p1) = self.size();
+        let (p2, m) = other.view().size();
+        if p1 != p2 {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Sizes not conform to multiplication"
+                    .to_string(),
+            });
+        }
+        out.reset(n, m);
+        gemm(K::one(), self, other, K::default(), out)
+    }
+}
+
+impl<K> Vector<K>
+where
+    K: Field,
+{
+    // Add a vector of the same size in place
+    pub fn add_assign(
+        &mut self,
+        other: &Vector<K>,
+    ) -> Result<(), LinAlgError> {
+        if self.size() != other.size() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "sum".to_string(),
+                },
+            );
+        }
+        self.matrix.add_assign(&other.matrix)
+    }
+    // Subtract a vector of the same size in place
+    pub fn sub_assign(
+        &mut self,
+        other: &Vector<K>,
+    ) -> Result<(), LinAlgError> {
+        if self.size() != other.size() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "sub".to_string(),
+                },
+            );
+        }
+        self.matrix.sub_assign(&other.matrix)
+    }
+    // Add a·x to the vector in place
+    pub fn axpy(
+        &mut self,
+        a: K,
+        x: &Vector<K>,
+    ) -> Result<(), LinAlgError> {
+        self.matrix.axpy(a, &x.matrix)
+    }
+    // Scale the vector by a factor in place
+    pub fn scale_mut(
+        &mut self,
+        scaling: K,
+    ) -> Result<(), LinAlgError> {
+        self.matrix.scale_mut(scaling)
+    }
+    // Write self + other into `out`, reusing its allocation
+    pub fn add_into(
+        &self,
+        other: &Vector<K>,
+        out: &mut Vector<K>,
+    ) -> Result<(), LinAlgError> {
+        if self.size() != other.size() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "sum".to_string(),
+                },
+            );
+        }
+        self.matrix
+            .add_into(&other.matrix, &mut out.matrix)?;
+        out.sync_size();
+        Ok(())
+    }
+    // Write self - other into `out`, reusing its allocation
+    pub fn sub_into(
+        &self,
+        other: &Vector<K>,
+        out: &mut Vector<K>,
+    ) -> Result<(), LinAlgError> {
+        if self.size() != other.size() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "sub".to_string(),
+                },
+            );
+        }
+        self.matrix
+            .sub_into(&other.matrix, &mut out.matrix)?;
+        out.sync_size();
+        Ok(())
+    }
+    // Write scaling·self into `out`, reusing its allocation
+    pub fn scl_into(
+        &self,
+        scaling: K,
+        out: &mut Vector<K>,
+    ) -> Result<(), LinAlgError> {
+        self.matrix.scl_into(scaling, &mut out.matrix)?;
+        out.sync_size();
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{
+    errors::LinAlgError, io::io_error, matrix::Matrix,
+};
+use std::io::{BufRead, BufReader, Read, Write};
+
+// What to do with an empty or `NA` field
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MissingValues {
+    // Fail with the line and column of the field
+    Error,
+    // Replace it with a fixed value, e.g. `f64::NAN`
+    Fill(f64),
+    // Drop the whole row
+    SkipRow,
+}
+
+// How a CSV file is laid out
+#[derive(Clone, Copy)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    // The first line holds the column names
+    pub has_header: bool,
+    pub missing: MissingValues,
+}
+
+impl Default for CsvOptions {
+    fn default() -> CsvOptions {
+        CsvOptions {
+            delimiter: ',',
+            has_header: false,
+            missing: MissingValues::Error,
+        }
+    }
+}
+
+fn parse_error(
+    line: usize,
+    message: String,
+) -> LinAlgError {
+    LinAlgError::Parse { line, message }
+}
+
+// Split a record on the delimiter; fields may be wrapped in double quotes,
+// with "" standing for a quote inside them. None while a quoted field is
+// still open, so the record continues on the next line.
+fn split_fields(
+    record: &str,
+    delimiter: char,
+) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = record.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' && field.trim().is_empty() {
+            field.clear();
+            quoted = true;
+        } else if c == delimiter {
+            fields.push(std::mem::take(&mut field));
+        } else {
+            field.push(c);
+        }
+    }
+    if quoted {
+        return None;
+    }
+    fields.push(field);
+    Some(fields)
+}
+
+// Quote a field when writing it as is would change how it splits
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter)
+        || field.contains('"')
+        || field.contains('\n')
+    {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl Matrix<f64> {
+    // Read one row per record and one column per field; a record is a
+    // line unless a quoted field spans several. With a header the column
+    // names are returned alongside the matrix.
+    pub fn from_csv<R>(
+        reader: R,
+        options: &CsvOptions,
+    ) -> Result<
+        (Matrix<f64>, Option<Vec<String>>),
+        LinAlgError,
+    >
+    where
+        R: Read,
+    {
+        let mut names: Option<Vec<String>> = None;
+        let mut width: Option<usize> = None;
+        let mut rows: Vec<Vec<f64>> = Vec::new();
+        // an unfinished record and the line it started on
+        let mut pending: Option<(usize, String)> = None;
+        'lines: for (k, line) in
+            BufReader::new(reader).lines().enumerate()
+        {
+            let line = line.map_err(io_error(k + 1))?;
+            let line = line.trim_end_matches('\r');
+            let (line_number, record) = match pending.take()
+            {
+                Some((start, mut record)) => {
+                    record.push('\n');
+                    record.push_str(line);
+                    (start, record)
+                }
+                None if line.trim().is_empty() => continue,
+                None => (k + 1, line.to_string()),
+            };
+            let fields = match split_fields(
+                &record,
+                options.delimiter,
+            ) {
+                Some(fields) => fields,
+                None => {
+                    pending = Some((line_number, record));
+                    continue;
+                }
+            };
+            if options.has_header && names.is_none() {
+                width = Some(fields.len());
+                names = Some(
+                    fields
+                        .iter()
+                        .map(|x| x.trim().to_string())
+                        .collect(),
+                );
+                continue;
+            }
+            let expected =
+                *width.get_or_insert(fields.len());
+            if fields.len() != expected {
+                return Err(parse_error(
+                    line_number,
+                    format!(
+                        "expected {} fields got {}",
+                        expected,
+                        fields.len()
+                    ),
+                ));
+            }
+            let mut row = Vec::with_capacity(expected);
+            for (j, field) in fields.iter().enumerate() {
+                let field = field.trim();
+                if field.is_empty() || field == "NA" {
+                    match options.missing {
+                        MissingValues::Error => {
+                            return Err(parse_error(
+                                line_number,
+                                format!(
+                                    "column {}: missing value",
+                                    j + 1
+                                ),
+                            ))
+                        }
+                        MissingValues::Fill(x) => row.push(x),
+                        MissingValues::SkipRow => {
+                            continue 'lines
+                        }
+                    }
+                    continue;
+                }
+                match field.parse::<f64>() {
+                    Ok(x) => row.push(x),
+                    Err(_) => {
+                        return Err(parse_error(
+                            line_number,
+                            format!(
+                                "column {}: invalid number {:?}",
+                                j + 1,
+                                field
+                            ),
+                        ))
+                    }
+                }
+            }
+            rows.push(row);
+        }
+        if let Some((line_number, _)) = pending {
+            return Err(parse_error(
+                line_number,
+                "unterminated quoted field".to_string(),
+            ));
+        }
+
+        let (n, m) = (rows.len(), width.unwrap_or(0));
+        let mut elements = vec![0.; n * m];
+        for (i, row) in rows.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                elements[i + j * n] = *x;
+            }
+        }
+        Ok((Matrix::new(elements, n, m)?, names))
+    }
+
+    // Write one row per line, after a header when column names are given
+    pub fn to_csv<W>(
+        &self,
+        writer: W,
+        options: &CsvOptions,
+        column_names: Option<&[String]>,
+    ) -> Result<(), LinAlgError>
+    where
+        W: Write,
+    {
+        let (n, m) = self.size();
+        let delimiter = options.delimiter.to_string();
+        let mut writer = std::io::BufWriter::new(writer);
+        if let Some(names) = column_names {
+            if names.len() != m {
+                return Err(
+                    LinAlgError::OperationNonConforming {
+                        operation: "CSV header".to_string(),
+                    },
+                );
+            }
+            let header: Vec<String> = names
+                .iter()
+                .map(|x| quote_field(x, options.delimiter))
+                .collect();
+            writeln!(writer, "{}", header.join(&delimiter))
+                .map_err(io_error(1))?;
+        }
+        let first = usize::from(column_names.is_some()) + 1;
+        for i in 0..n {
+            let row: Vec<String> = (0..m)
+                .map(|j| {
+                    self.elements[i + j * n].to_string()
+                })
+                .collect();
+            writeln!(writer, "{}", row.join(&delimiter))
+                .map_err(io_error(first + i))?;
+        }
+        writer.flush().map_err(io_error(first + n - 1))?;
+        Ok(())
+    }
+}
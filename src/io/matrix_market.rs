@@ -0,0 +1,433 @@
+use crate::{
+    complex::ComplexNumber,
+    errors::LinAlgError,
+    io::io_error,
+    matrix::Matrix,
+    sparse::{CooBuilder, CsrMatrix},
+    traits::Field,
+};
+use std::io::{BufRead, BufReader, Read, Write};
+
+// Entries reserved up front when reading, whatever the header announces
+const MAX_RESERVED: usize = 1 << 16;
+
+// Scalars that can be stored in a Matrix Market file
+pub trait MatrixMarketField: Field {
+    // The `field` written in the header
+    const FIELD: &'static str;
+    // Build from the real part and, for complex files, the imaginary one
+    fn from_parts(re: f64, im: Option<f64>)
+        -> Option<Self>;
+    // The value as written in an entry line
+    fn format_entry(&self) -> String;
+}
+
+impl MatrixMarketField for f64 {
+    const FIELD: &'static str = "real";
+    fn from_parts(re: f64, im: Option<f64>) -> Option<f64> {
+        match im {
+            None => Some(re),
+            Some(_) => None,
+        }
+    }
+    fn format_entry(&self) -> String {
+        format!("{:e}", self)
+    }
+}
+
+impl MatrixMarketField for f32 {
+    const FIELD: &'static str = "real";
+    fn from_parts(re: f64, im: Option<f64>) -> Option<f32> {
+        match im {
+            None => Some(re as f32),
+            Some(_) => None,
+        }
+    }
+    fn format_entry(&self) -> String {
+        format!("{:e}", self)
+    }
+}
+
+impl MatrixMarketField for ComplexNumber {
+    const FIELD: &'static str = "complex";
+    fn from_parts(
+        re: f64,
+        im: Option<f64>,
+    ) -> Option<ComplexNumber> {
+        Some(ComplexNumber::n(re, im.unwrap_or(0.0)))
+    }
+    fn format_entry(&self) -> String {
+        format!("{:e} {:e}", self.re(), self.im())
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Layout {
+    Coordinate,
+    Array,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Entries {
+    Real,
+    Integer,
+    Complex,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+fn parse_error(line: usize, message: &str) -> LinAlgError {
+    LinAlgError::Parse {
+        line,
+        message: message.to_string(),
+    }
+}
+
+// Read a Matrix Market file into a builder, from which the dense or sparse
+// matrix is made. Symmetric, skew-symmetric and Hermitian files only store
+// one triangle; the other one is filled in here.
+pub fn read_mtx<K, R>(
+    reader: R,
+) -> Result<CooBuilder<K>, LinAlgError>
+where
+    K: MatrixMarketField,
+    R: Read,
+{
+    let mut lines = BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .map(|(k, line)| (k + 1, line));
+
+    let (line_number, header) = match lines.next() {
+        Some((k, line)) => (k, line.map_err(io_error(k))?),
+        None => return Err(parse_error(1, "empty file")),
+    };
+    let (layout, entries, symmetry) =
+        parse_header(line_number, &header)?;
+
+    // the size line is the first one that isn't a comment
+    let mut last_line = line_number;
+    let mut size_line = None;
+    for (k, line) in lines.by_ref() {
+        let line = line.map_err(io_error(k))?;
+        last_line = k;
+        let trimmed = line.trim();
+        if !trimmed.is_empty() && !trimmed.starts_with('%')
+        {
+            size_line = Some((k, line));
+            break;
+        }
+    }
+    let (line_number, size_line) = match size_line {
+        Some(found) => found,
+        None => {
+            return Err(parse_error(
+                last_line + 1,
+                "missing size line",
+            ))
+        }
+    };
+    let size: Vec<usize> = size_line
+        .split_whitespace()
+        .map(|x| x.parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| {
+            parse_error(line_number, "invalid size line")
+        })?;
+    let expected_len = match layout {
+        Layout::Coordinate => 3,
+        Layout::Array => 2,
+    };
+    if size.len() != expected_len {
+        return Err(parse_error(
+            line_number,
+            "invalid size line",
+        ));
+    }
+    let (n, m) = (size[0], size[1]);
+    if symmetry != Symmetry::General && n != m {
+        return Err(parse_error(
+            line_number,
+            "a symmetric matrix must be square",
+        ));
+    }
+    // an array file lists one triangle for the symmetric kinds, column by
+    // column starting at row `first(j)`
+    let first = |j: usize| match symmetry {
+        Symmetry::General => 1,
+        Symmetry::SkewSymmetric => j + 1,
+        _ => j,
+    };
+    let count = match layout {
+        Layout::Coordinate => Some(size[2]),
+        Layout::Array => match symmetry {
+            Symmetry::General => n.checked_mul(m),
+            Symmetry::SkewSymmetric => n
+                .checked_mul(n.saturating_sub(1))
+                .map(|x| x / 2),
+            _ => n.checked_mul(n + 1).map(|x| x / 2),
+        },
+    }
+    .ok_or_else(|| {
+        parse_error(line_number, "matrix too large")
+    })?;
+    // the header can't be trusted, so only reserve room for part of it
+    // and let the rest grow with the entries actually read
+    let mut builder = CooBuilder::with_capacity(
+        n,
+        m,
+        count.min(MAX_RESERVED),
+    );
+    // next position of an array file, in column-major order
+    let (mut row, mut col) = (first(1), 1);
+    while row > n && col < m {
+        col += 1;
+        row = first(col);
+    }
+    let mut read = 0;
+    last_line = line_number;
+    for (k, line) in lines {
+        let line = line.map_err(io_error(k))?;
+        last_line = k;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('%') {
+            continue;
+        }
+        if read == count {
+            return Err(parse_error(k, "too many entries"));
+        }
+        let tokens: Vec<&str> =
+            trimmed.split_whitespace().collect();
+        let (i, j, values) = match layout {
+            Layout::Coordinate => {
+                if tokens.len() < 2 {
+                    return Err(parse_error(
+                        k,
+                        "missing indices",
+                    ));
+                }
+                let index = |x: &str| {
+                    x.parse::<usize>().map_err(|_| {
+                        parse_error(k, "invalid index")
+                    })
+                };
+                (
+                    index(tokens[0])?,
+                    index(tokens[1])?,
+                    &tokens[2..],
+                )
+            }
+            Layout::Array => {
+                let (i, j) = (row, col);
+                row += 1;
+                while row > n && col < m {
+                    col += 1;
+                    row = first(col);
+                }
+                (i, j, &tokens[..])
+            }
+        };
+        if i == 0 || j == 0 || i > n || j > m {
+            return Err(parse_error(
+                k,
+                "index out of bounds",
+            ));
+        }
+        let v = parse_value::<K>(k, entries, values)?;
+        read += 1;
+        if layout == Layout::Array && v == K::default() {
+            continue;
+        }
+        builder.push(i, j, v)?;
+        if i != j {
+            match symmetry {
+                Symmetry::General => (),
+                Symmetry::Symmetric => {
+                    builder.push(j, i, v)?
+                }
+                Symmetry::SkewSymmetric => {
+                    builder.push(j, i, K::default() - v)?
+                }
+                Symmetry::Hermitian => {
+                    builder.push(j, i, v.conj())?
+                }
+            }
+        }
+    }
+    if read < count {
+        return Err(parse_error(
+            last_line + 1,
+            &format!(
+                "expected {} entries got {}",
+                count, read
+            ),
+        ));
+    }
+    Ok(builder)
+}
+
+fn parse_header(
+    line: usize,
+    header: &str,
+) -> Result<(Layout, Entries, Symmetry), LinAlgError> {
+    let tokens: Vec<String> = header
+        .split_whitespace()
+        .map(|x| x.to_lowercase())
+        .collect();
+    if tokens.len() != 5
+        || tokens[0] != "%%matrixmarket"
+        || tokens[1] != "matrix"
+    {
+        return Err(parse_error(
+            line,
+            "expected a %%MatrixMarket matrix header",
+        ));
+    }
+    let layout = match tokens[2].as_str() {
+        "coordinate" => Layout::Coordinate,
+        "array" => Layout::Array,
+        other => {
+            return Err(parse_error(
+                line,
+                &format!("unsupported format {}", other),
+            ))
+        }
+    };
+    let entries = match tokens[3].as_str() {
+        "real" => Entries::Real,
+        "integer" => Entries::Integer,
+        "complex" => Entries::Complex,
+        other => {
+            return Err(parse_error(
+                line,
+                &format!("unsupported field {}", other),
+            ))
+        }
+    };
+    let symmetry = match tokens[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => Symmetry::Hermitian,
+        other => {
+            return Err(parse_error(
+                line,
+                &format!("unsupported symmetry {}", other),
+            ))
+        }
+    };
+    Ok((layout, entries, symmetry))
+}
+
+fn parse_value<K>(
+    line: usize,
+    entries: Entries,
+    tokens: &[&str],
+) -> Result<K, LinAlgError>
+where
+    K: MatrixMarketField,
+{
+    let expected = match entries {
+        Entries::Complex => 2,
+        _ => 1,
+    };
+    if tokens.len() != expected {
+        return Err(parse_error(
+            line,
+            &format!("expected {} values", expected),
+        ));
+    }
+    let number = |x: &str| match entries {
+        Entries::Integer => {
+            x.parse::<i64>().map(|x| x as f64).ok()
+        }
+        _ => x.parse::<f64>().ok(),
+    };
+    let re = number(tokens[0]).ok_or_else(|| {
+        parse_error(line, "invalid value")
+    })?;
+    let im = match entries {
+        Entries::Complex => {
+            Some(number(tokens[1]).ok_or_else(|| {
+                parse_error(line, "invalid value")
+            })?)
+        }
+        _ => None,
+    };
+    K::from_parts(re, im).ok_or_else(|| {
+        parse_error(
+            line,
+            "complex entries need a complex matrix",
+        )
+    })
+}
+
+// Write a dense matrix in the array layout, column by column
+pub fn write_mtx<K, W>(
+    writer: W,
+    a: &Matrix<K>,
+) -> Result<(), LinAlgError>
+where
+    K: MatrixMarketField,
+    W: Write,
+{
+    let mut writer = std::io::BufWriter::new(writer);
+    let (n, m) = a.size();
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix array {} general",
+        K::FIELD
+    )
+    .map_err(io_error(1))?;
+    writeln!(writer, "{} {}", n, m).map_err(io_error(2))?;
+    for (k, x) in a.elements.iter().enumerate() {
+        writeln!(writer, "{}", x.format_entry())
+            .map_err(io_error(k + 3))?;
+    }
+    writer
+        .flush()
+        .map_err(io_error(a.elements.len() + 2))?;
+    Ok(())
+}
+
+// Write a sparse matrix in the coordinate layout, row by row
+pub fn write_mtx_sparse<K, W>(
+    writer: W,
+    a: &CsrMatrix<K>,
+) -> Result<(), LinAlgError>
+where
+    K: MatrixMarketField,
+    W: Write,
+{
+    let mut writer = std::io::BufWriter::new(writer);
+    let (n, m) = a.size();
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix coordinate {} general",
+        K::FIELD
+    )
+    .map_err(io_error(1))?;
+    writeln!(writer, "{} {} {}", n, m, a.nnz())
+        .map_err(io_error(2))?;
+    // entries are stored row by row, so entry k is on line k + 3
+    for i in 1..=n {
+        for k in a.row_range(i) {
+            writeln!(
+                writer,
+                "{} {} {}",
+                i,
+                a.col_indices()[k],
+                a.values()[k].format_entry()
+            )
+            .map_err(io_error(k + 3))?;
+        }
+    }
+    writer.flush().map_err(io_error(a.nnz() + 2))?;
+    Ok(())
+}
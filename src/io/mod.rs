@@ -0,0 +1,18 @@
+// Reading and writing matrices in formats other tools understand
+pub mod csv;
+pub mod matrix_market;
+pub mod npy;
+pub mod npz;
+
+use crate::errors::LinAlgError;
+
+// Reader and writer failures surface as a `Parse` error on the line being
+// read or written when they show up; binary formats are a single line
+pub(crate) fn io_error(
+    line: usize,
+) -> impl Fn(std::io::Error) -> LinAlgError {
+    move |error| LinAlgError::Parse {
+        line,
+        message: format!("I/O error: {}", error),
+    }
+}
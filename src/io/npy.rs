@@ -0,0 +1,319 @@
+use crate::{
+    complex::ComplexNumber, errors::LinAlgError,
+    io::io_error, matrix::Matrix, traits::Field,
+};
+use std::io::{Read, Write};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Scalars with a NumPy dtype
+pub trait NpyElement: Field {
+    // dtype without the byte order, e.g. `f8`
+    const DTYPE: &'static str;
+    // Bytes of one element
+    const SIZE: usize;
+    // Bytes of each real component, swapped as a unit for big endian data
+    const COMPONENT: usize;
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+    fn write_le_bytes(&self, out: &mut Vec<u8>);
+}
+
+impl NpyElement for f64 {
+    const DTYPE: &'static str = "f8";
+    const SIZE: usize = 8;
+    const COMPONENT: usize = 8;
+    fn from_le_bytes(bytes: &[u8]) -> f64 {
+        let mut raw = [0; 8];
+        raw.copy_from_slice(bytes);
+        f64::from_le_bytes(raw)
+    }
+    fn write_le_bytes(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl NpyElement for f32 {
+    const DTYPE: &'static str = "f4";
+    const SIZE: usize = 4;
+    const COMPONENT: usize = 4;
+    fn from_le_bytes(bytes: &[u8]) -> f32 {
+        let mut raw = [0; 4];
+        raw.copy_from_slice(bytes);
+        f32::from_le_bytes(raw)
+    }
+    fn write_le_bytes(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl NpyElement for ComplexNumber {
+    const DTYPE: &'static str = "c16";
+    const SIZE: usize = 16;
+    const COMPONENT: usize = 8;
+    fn from_le_bytes(bytes: &[u8]) -> ComplexNumber {
+        ComplexNumber::n(
+            <f64 as NpyElement>::from_le_bytes(&bytes[..8]),
+            <f64 as NpyElement>::from_le_bytes(&bytes[8..]),
+        )
+    }
+    fn write_le_bytes(&self, out: &mut Vec<u8>) {
+        self.re().write_le_bytes(out);
+        self.im().write_le_bytes(out);
+    }
+}
+
+// What the header dictionary describes
+struct Header {
+    little_endian: bool,
+    fortran_order: bool,
+    shape: (usize, usize),
+}
+
+fn header_error(message: String) -> LinAlgError {
+    // the header is the first and only text line of the file
+    LinAlgError::Parse { line: 1, message }
+}
+
+// The text following `'key':` in the header dictionary
+fn header_value<'a>(
+    header: &'a str,
+    key: &str,
+) -> Result<&'a str, LinAlgError> {
+    let quoted = format!("'{}'", key);
+    let start = header.find(&quoted).ok_or_else(|| {
+        header_error(format!("missing key {}", quoted))
+    })?;
+    let rest = header[start + quoted.len()..].trim_start();
+    match rest.strip_prefix(':') {
+        Some(value) => Ok(value.trim_start()),
+        None => Err(header_error(format!(
+            "expected ':' after {}",
+            quoted
+        ))),
+    }
+}
+
+fn parse_header<K>(
+    header: &str,
+) -> Result<Header, LinAlgError>
+where
+    K: NpyElement,
+{
+    let descr = header_value(header, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|x| x.split('\'').next())
+        .ok_or_else(|| {
+            header_error("invalid descr".to_string())
+        })?;
+    let (order, dtype) = descr.split_at(descr.len().min(1));
+    // single byte types have no order and are written with '|'
+    let little_endian = match order {
+        "<" | "|" => true,
+        ">" => false,
+        _ => {
+            return Err(header_error(format!(
+                "invalid descr '{}'",
+                descr
+            )))
+        }
+    };
+    if dtype != K::DTYPE {
+        return Err(header_error(format!(
+            "expected dtype {} got {}",
+            K::DTYPE,
+            dtype
+        )));
+    }
+
+    let fortran = header_value(header, "fortran_order")?;
+    let fortran_order = if fortran.starts_with("True") {
+        true
+    } else if fortran.starts_with("False") {
+        false
+    } else {
+        return Err(header_error(
+            "invalid fortran_order".to_string(),
+        ));
+    };
+
+    let shape = header_value(header, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|x| x.split(')').next())
+        .ok_or_else(|| {
+            header_error("invalid shape".to_string())
+        })?;
+    let dims: Vec<usize> = shape
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| {
+            header_error(format!(
+                "invalid shape ({})",
+                shape
+            ))
+        })?;
+    // scalars and 1D arrays become a single column
+    let shape = match dims[..] {
+        [] => (1, 1),
+        [n] => (n, 1),
+        [n, m] => (n, m),
+        _ => {
+            return Err(header_error(format!(
+                "expected at most 2 dimensions got {}",
+                dims.len()
+            )))
+        }
+    };
+    Ok(Header {
+        little_endian,
+        fortran_order,
+        shape,
+    })
+}
+
+// Read a .npy array of dtype K. C-ordered data is transposed into the
+// column-major `elements`; Fortran-ordered data is copied as is.
+pub fn read_npy<K, R>(
+    mut reader: R,
+) -> Result<Matrix<K>, LinAlgError>
+where
+    K: NpyElement,
+    R: Read,
+{
+    let mut preamble = [0u8; 8];
+    reader
+        .read_exact(&mut preamble)
+        .map_err(io_error(1))?;
+    if &preamble[..6] != MAGIC {
+        return Err(header_error(
+            "not a .npy file".to_string(),
+        ));
+    }
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader
+                .read_exact(&mut len)
+                .map_err(io_error(1))?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader
+                .read_exact(&mut len)
+                .map_err(io_error(1))?;
+            u32::from_le_bytes(len) as usize
+        }
+        version => {
+            return Err(header_error(format!(
+                "unsupported format version {}",
+                version
+            )))
+        }
+    };
+    let mut header = vec![0u8; header_len];
+    reader.read_exact(&mut header).map_err(io_error(1))?;
+    let header = String::from_utf8_lossy(&header);
+    let Header {
+        little_endian,
+        fortran_order,
+        shape: (n, m),
+    } = parse_header::<K>(&header)?;
+
+    // the shape is untrusted, so read what is there rather than allocating
+    // what the header claims
+    let len = n
+        .checked_mul(m)
+        .and_then(|x| x.checked_mul(K::SIZE))
+        .ok_or_else(|| {
+            header_error(format!(
+                "shape ({}, {}) is too large",
+                n, m
+            ))
+        })?;
+    let mut data = Vec::new();
+    reader
+        .take(len as u64)
+        .read_to_end(&mut data)
+        .map_err(io_error(1))?;
+    if data.len() != len {
+        return Err(header_error(format!(
+            "expected {} bytes of data got {}",
+            len,
+            data.len()
+        )));
+    }
+    if !little_endian {
+        for component in data.chunks_mut(K::COMPONENT) {
+            component.reverse();
+        }
+    }
+    let values: Vec<K> = data
+        .chunks(K::SIZE)
+        .map(K::from_le_bytes)
+        .collect();
+    if fortran_order {
+        return Matrix::new(values, n, m);
+    }
+    let mut elements = vec![K::default(); n * m];
+    for i in 0..n {
+        for j in 0..m {
+            elements[i + j * n] = values[i * m + j];
+        }
+    }
+    Matrix::new(elements, n, m)
+}
+
+// Write a matrix as a Fortran-ordered .npy array, so `elements` is the data
+pub fn write_npy<K, W>(
+    mut writer: W,
+    a: &Matrix<K>,
+) -> Result<(), LinAlgError>
+where
+    K: NpyElement,
+    W: Write,
+{
+    let (n, m) = a.size();
+    let mut header = format!(
+        "{{'descr': '<{}', 'fortran_order': True, 'shape': ({}, {}), }}",
+        K::DTYPE,
+        n,
+        m
+    );
+    // version 1 stores the header length in 2 bytes, version 2 in 4;
+    // either way the data starts on a multiple of 64 bytes
+    let (version, prefix) =
+        if header.len() + 64 <= u16::MAX as usize {
+            (1u8, MAGIC.len() + 4)
+        } else {
+            (2u8, MAGIC.len() + 6)
+        };
+    let padding = 63 - (prefix + header.len()) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let mut out = Vec::with_capacity(
+        prefix + header.len() + n * m * K::SIZE,
+    );
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&[version, 0]);
+    if version == 1 {
+        out.extend_from_slice(
+            &(header.len() as u16).to_le_bytes(),
+        );
+    } else {
+        out.extend_from_slice(
+            &(header.len() as u32).to_le_bytes(),
+        );
+    }
+    out.extend_from_slice(header.as_bytes());
+    for x in &a.elements {
+        x.write_le_bytes(&mut out);
+    }
+    writer.write_all(&out).map_err(io_error(1))?;
+    Ok(())
+}
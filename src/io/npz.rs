@@ -0,0 +1,223 @@
+use crate::{
+    errors::LinAlgError,
+    io::io_error,
+    io::npy::{read_npy, write_npy, NpyElement},
+    matrix::Matrix,
+};
+use std::io::{Read, Write};
+
+const LOCAL_HEADER: u32 = 0x04034b50;
+const CENTRAL_HEADER: u32 = 0x02014b50;
+const END_OF_DIRECTORY: u32 = 0x06054b50;
+
+// CRC-32 as used by zip, bit by bit since archives are small
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb88320 & mask);
+        }
+    }
+    !crc
+}
+
+fn archive_error(message: &str) -> LinAlgError {
+    // an archive has no lines, report it like a .npy header
+    LinAlgError::Parse {
+        line: 1,
+        message: format!("npz: {}", message),
+    }
+}
+
+// A size or count stored in a fixed-width zip field; without zip64
+// records, a value that doesn't fit can't be written
+fn field<T>(
+    value: usize,
+    what: &str,
+) -> Result<T, LinAlgError>
+where
+    T: TryFrom<usize>,
+{
+    T::try_from(value).map_err(|_| {
+        archive_error(&format!(
+            "{} too large for a zip archive",
+            what
+        ))
+    })
+}
+
+fn u16_at(
+    data: &[u8],
+    at: usize,
+) -> Result<u16, LinAlgError> {
+    data.get(at..at + 2)
+        .map(|x| u16::from_le_bytes([x[0], x[1]]))
+        .ok_or_else(|| archive_error("truncated archive"))
+}
+
+fn u32_at(
+    data: &[u8],
+    at: usize,
+) -> Result<u32, LinAlgError> {
+    data.get(at..at + 4)
+        .map(|x| {
+            u32::from_le_bytes([x[0], x[1], x[2], x[3]])
+        })
+        .ok_or_else(|| archive_error("truncated archive"))
+}
+
+// Read every array of an uncompressed .npz archive, in archive order, with
+// the `.npy` suffix dropped from the names
+pub fn read_npz<K, R>(
+    mut reader: R,
+) -> Result<Vec<(String, Matrix<K>)>, LinAlgError>
+where
+    K: NpyElement,
+    R: Read,
+{
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).map_err(io_error(1))?;
+    // the end of central directory record is last, before a comment of
+    // at most 65535 bytes
+    let lowest = data.len().saturating_sub(22 + 65535);
+    let end = (lowest..=data.len().saturating_sub(22))
+        .rev()
+        .find(|&at| {
+            u32_at(&data, at).ok() == Some(END_OF_DIRECTORY)
+        })
+        .ok_or_else(|| {
+            archive_error("not a zip archive")
+        })?;
+    let entries = u16_at(&data, end + 10)? as usize;
+    let mut at = u32_at(&data, end + 16)? as usize;
+
+    let mut to_return = Vec::with_capacity(entries);
+    for _ in 0..entries {
+        if u32_at(&data, at)? != CENTRAL_HEADER {
+            return Err(archive_error(
+                "corrupt central directory",
+            ));
+        }
+        let method = u16_at(&data, at + 10)?;
+        let crc = u32_at(&data, at + 16)?;
+        let size = u32_at(&data, at + 20)? as usize;
+        let name_len = u16_at(&data, at + 28)? as usize;
+        let extra_len = u16_at(&data, at + 30)? as usize;
+        let comment_len = u16_at(&data, at + 32)? as usize;
+        let offset = u32_at(&data, at + 42)? as usize;
+        let name = data
+            .get(at + 46..at + 46 + name_len)
+            .ok_or_else(|| {
+                archive_error("truncated archive")
+            })?;
+        let name =
+            String::from_utf8_lossy(name).to_string();
+        at += 46 + name_len + extra_len + comment_len;
+        if method != 0 {
+            return Err(archive_error(&format!(
+                "{} is compressed, only stored entries are supported",
+                name
+            )));
+        }
+
+        if u32_at(&data, offset)? != LOCAL_HEADER {
+            return Err(archive_error(
+                "corrupt local header",
+            ));
+        }
+        let start = offset
+            + 30
+            + u16_at(&data, offset + 26)? as usize
+            + u16_at(&data, offset + 28)? as usize;
+        let contents =
+            data.get(start..start + size).ok_or_else(
+                || archive_error("truncated archive"),
+            )?;
+        if crc32(contents) != crc {
+            return Err(archive_error(&format!(
+                "checksum mismatch in {}",
+                name
+            )));
+        }
+        let name = match name.strip_suffix(".npy") {
+            Some(stem) => stem.to_string(),
+            None => name,
+        };
+        to_return.push((name, read_npy(contents)?));
+    }
+    Ok(to_return)
+}
+
+// Write named matrices as an uncompressed .npz archive, like numpy.savez
+pub fn write_npz<K, W>(
+    mut writer: W,
+    arrays: &[(&str, &Matrix<K>)],
+) -> Result<(), LinAlgError>
+where
+    K: NpyElement,
+    W: Write,
+{
+    let mut out = Vec::new();
+    let mut directory = Vec::new();
+    for (name, a) in arrays {
+        let name = format!("{}.npy", name);
+        let mut contents = Vec::new();
+        write_npy(&mut contents, a)?;
+        let size = field::<u32>(contents.len(), "entry")?;
+        let name_len = field::<u16>(name.len(), "name")?;
+        let crc = crc32(&contents);
+        let offset = field::<u32>(out.len(), "archive")?;
+
+        // version 2.0, no flags, stored, DOS date 1980-01-01
+        let common = |record: &mut Vec<u8>| {
+            record.extend_from_slice(&20u16.to_le_bytes());
+            record.extend_from_slice(&0u16.to_le_bytes());
+            record.extend_from_slice(&0u16.to_le_bytes());
+            record.extend_from_slice(&0u16.to_le_bytes());
+            record
+                .extend_from_slice(&0x21u16.to_le_bytes());
+            record.extend_from_slice(&crc.to_le_bytes());
+            record.extend_from_slice(&size.to_le_bytes());
+            record.extend_from_slice(&size.to_le_bytes());
+            record
+                .extend_from_slice(&name_len.to_le_bytes());
+            record.extend_from_slice(&0u16.to_le_bytes());
+        };
+
+        out.extend_from_slice(&LOCAL_HEADER.to_le_bytes());
+        common(&mut out);
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(&contents);
+
+        directory.extend_from_slice(
+            &CENTRAL_HEADER.to_le_bytes(),
+        );
+        // made by version 2.0
+        directory.extend_from_slice(&20u16.to_le_bytes());
+        common(&mut directory);
+        // comment length, disk, internal and external attributes
+        directory.extend_from_slice(&[0; 10]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    let directory_offset =
+        field::<u32>(out.len(), "archive")?;
+    let directory_len =
+        field::<u32>(directory.len(), "directory")?;
+    let entries =
+        field::<u16>(arrays.len(), "entry count")?
+            .to_le_bytes();
+    out.extend_from_slice(&directory);
+    out.extend_from_slice(&END_OF_DIRECTORY.to_le_bytes());
+    out.extend_from_slice(&[0; 4]);
+    out.extend_from_slice(&entries);
+    out.extend_from_slice(&entries);
+    out.extend_from_slice(&directory_len.to_le_bytes());
+    out.extend_from_slice(&directory_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    writer.write_all(&out).map_err(io_error(1))?;
+    Ok(())
+}
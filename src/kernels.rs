@@ -0,0 +1,162 @@
+// Kernels used by the hot loops. `Kernels` is a supertrait of `Field` but
+// lives in a private module, so it can't be named, implemented or
+// overridden outside the crate; f32 and f64 route to the vectorized
+// versions on x86_64.
+use crate::{
+    complex::ComplexNumber,
+    traits::{Conj, Norm},
+};
+use ::core::ops::{Add, Mul};
+use std::cell::RefCell;
+
+#[cfg(target_arch = "x86_64")]
+use crate::simd;
+
+pub trait Kernels:
+    Add<Output = Self>
+    + Mul<Output = Self>
+    + Copy
+    + Default
+    + Conj
+    + Norm
+{
+    // Σ conj(xᵢ)·yᵢ over two slices of the same length
+    fn dot(x: &[Self], y: &[Self]) -> Self {
+        let mut to_return = Self::default();
+        for (a, b) in x.iter().zip(y) {
+            to_return = to_return + a.conj() * *b;
+        }
+        to_return
+    }
+    // y ← a·x + y
+    fn axpy(a: Self, x: &[Self], y: &mut [Self]) {
+        for (xi, yi) in x.iter().zip(y.iter_mut()) {
+            *yi = *yi + a * *xi;
+        }
+    }
+    // Σ |xᵢ|
+    fn asum(x: &[Self]) -> f64 {
+        let mut to_return = 0.0;
+        for xi in x {
+            to_return += xi.norm();
+        }
+        to_return
+    }
+    // max |xᵢ|, zero for an empty slice
+    fn amax(x: &[Self]) -> f64 {
+        let mut to_return = 0.0;
+        for xi in x {
+            let norm = xi.norm();
+            if norm > to_return {
+                to_return = norm;
+            }
+        }
+        to_return
+    }
+    // Run f on a scratch buffer of len elements with unspecified contents,
+    // kept per thread by the crate's types so repeated products don't
+    // allocate
+    fn with_scratch<R>(
+        len: usize,
+        f: impl FnOnce(&mut [Self]) -> R,
+    ) -> R {
+        f(&mut vec![Self::default(); len])
+    }
+    // GEMM register tile: acc[j][i] += a[4p + i]·b[4p + j] for every p
+    fn tile_4x4(
+        a: &[Self],
+        b: &[Self],
+        acc: &mut [[Self; 4]; 4],
+    ) {
+        for (a, b) in
+            a.chunks_exact(4).zip(b.chunks_exact(4))
+        {
+            for (column, bj) in acc.iter_mut().zip(b) {
+                for (x, ai) in column.iter_mut().zip(a) {
+                    *x = *x + *ai * *bj;
+                }
+            }
+        }
+    }
+}
+
+// One growing scratch buffer per thread; a nested call finds it borrowed
+// and falls back to a fresh one
+macro_rules! thread_scratch {
+    ($t:ty) => {
+        fn with_scratch<R>(
+            len: usize,
+            f: impl FnOnce(&mut [$t]) -> R,
+        ) -> R {
+            thread_local! {
+                static SCRATCH: RefCell<Vec<$t>> =
+                    const { RefCell::new(Vec::new()) };
+            }
+            SCRATCH.with(|scratch| {
+                match scratch.try_borrow_mut() {
+                    Ok(mut buffer) => {
+                        if buffer.len() < len {
+                            buffer.resize(
+                                len,
+                                <$t>::default(),
+                            );
+                        }
+                        f(&mut buffer[..len])
+                    }
+                    Err(_) => {
+                        f(&mut vec![<$t>::default(); len])
+                    }
+                }
+            })
+        }
+    };
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+impl Kernels for f64 {
+    thread_scratch!(f64);
+}
+#[cfg(not(target_arch = "x86_64"))]
+impl Kernels for f32 {
+    thread_scratch!(f32);
+}
+
+// Route the kernels to the SIMD implementations
+#[cfg(target_arch = "x86_64")]
+macro_rules! simd_kernels {
+    ($t:ty, $dot:ident, $axpy:ident, $asum:ident, $amax:ident, $tile:ident) => {
+        impl Kernels for $t {
+            thread_scratch!($t);
+            fn dot(x: &[$t], y: &[$t]) -> $t {
+                simd::$dot(x, y)
+            }
+            fn axpy(a: $t, x: &[$t], y: &mut [$t]) {
+                simd::$axpy(a, x, y)
+            }
+            fn asum(x: &[$t]) -> f64 {
+                simd::$asum(x)
+            }
+            fn amax(x: &[$t]) -> f64 {
+                simd::$amax(x)
+            }
+            fn tile_4x4(
+                a: &[$t],
+                b: &[$t],
+                acc: &mut [[$t; 4]; 4],
+            ) {
+                simd::$tile(a, b, acc)
+            }
+        }
+    };
+}
+#[cfg(target_arch = "x86_64")]
+simd_kernels!(
+    f64, dot_f64, axpy_f64, asum_f64, amax_f64, tile_f64
+);
+#[cfg(target_arch = "x86_64")]
+simd_kernels!(
+    f32, dot_f32, axpy_f32, asum_f32, amax_f32, tile_f32
+);
+impl Kernels for ComplexNumber {
+    thread_scratch!(ComplexNumber);
+}
@@ -1,6 +1,22 @@
 // src/lib.rs
 pub mod complex;
+pub mod decomposition;
 pub mod errors;
+pub mod format;
+pub mod gemm;
+mod inplace;
+pub mod io;
+mod kernels;
 pub mod matrix;
+mod ops;
+pub mod parallel;
+mod parse;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(target_arch = "x86_64")]
+mod simd;
+pub mod solvers;
+pub mod sparse;
 pub mod traits;
 pub mod vector;
+pub mod view;
@@ -1,9 +1,4 @@
-mod complex;
-mod errors;
-mod matrix;
 mod tests;
-mod traits;
-mod vector;
 
 use std::io;
 use tests::*;
@@ -29,6 +24,30 @@ fn main() {
         ex16_test,
         basic_stats_test,
         old_test,
+        lu_test,
+        solve_test,
+        qr_test,
+        cholesky_test,
+        lstsq_test,
+        eigh_test,
+        eig_test,
+        svd_test,
+        ops_test,
+        index_test,
+        views_test,
+        gemm_test,
+        parallel_test,
+        simd_test,
+        inplace_test,
+        sparse_test,
+        coo_test,
+        cg_test,
+        krylov_test,
+        matrix_market_test,
+        csv_test,
+        npy_test,
+        parse_test,
+        format_test,
     ];
     loop {
         println!("Enter a number of exercise to run the test for that part of the subject");
@@ -46,7 +65,7 @@ fn main() {
             println!("Bye!");
             break;
         }
-        let n = match usize::from_str_radix(trimmed, 10) {
+        let n = match trimmed.parse::<usize>() {
             Ok(num) => num,
             Err(_) => {
                 println!("Invalid input. Please enter a valid number");
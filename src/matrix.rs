@@ -2,7 +2,17 @@
 use core::fmt;
 
 use crate::{
-    errors::LinAlgError, traits::Field, vector::Vector,
+    complex::ComplexNumber,
+    decomposition::{
+        eig, lstsq, CholeskyDecomposition, LstsqSolution,
+        LuDecomposition, QrDecomposition,
+        SingularValueDecomposition, SymmetricEigen,
+    },
+    errors::LinAlgError,
+    parallel,
+    traits::Field,
+    vector::Vector,
+    view::AsMatrixView,
 };
 
 // Define a public struct `Matrix` with generic type `K` where `K` implements the `Field` trait
@@ -43,20 +53,28 @@ where
         self.size
     }
 
-    // Access a specific element of the matrix, with bounds checking
-    pub fn el(
+    // Position in `elements` of the 1-based entry (i, j), with bounds checking
+    pub(crate) fn offset(
         &self,
         i: usize,
         j: usize,
-    ) -> Result<K, LinAlgError> {
+    ) -> Result<usize, LinAlgError> {
         let (n, m) = self.size;
-        if i > n || j > m {
+        if i == 0 || j == 0 || i > n || j > m {
             return Err(LinAlgError::OutofBoundsMatrix {
                 size: self.size(),
                 recieved: (i, j),
             });
         }
-        Ok(self.elements[i - 1 + (j - 1) * n])
+        Ok(i - 1 + (j - 1) * n)
+    }
+    // Access a specific element of the matrix, with bounds checking
+    pub fn el(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Result<K, LinAlgError> {
+        Ok(self.elements[self.offset(i, j)?])
     }
     // Set the value of a specific element in the matrix, with bounds checking
     pub fn set(
@@ -65,15 +83,35 @@ where
         j: usize,
         val: K,
     ) -> Result<(), LinAlgError> {
+        let offset = self.offset(i, j)?;
+        self.elements[offset] = val;
+        Ok(())
+    }
+    // Borrow the 0-based entry (i, j) if it exists
+    pub fn get(&self, i: usize, j: usize) -> Option<&K> {
         let (n, m) = self.size;
-        if !(i <= n && j <= m) {
-            return Err(LinAlgError::OutofBoundsMatrix {
-                size: self.size(),
-                recieved: (i, j),
-            });
+        if i >= n || j >= m {
+            return None;
         }
-        self.elements[i - 1 + (j - 1) * n] = val;
-        Ok(())
+        self.elements.get(i + j * n)
+    }
+    // Mutably borrow the 0-based entry (i, j) if it exists
+    pub fn get_mut(
+        &mut self,
+        i: usize,
+        j: usize,
+    ) -> Option<&mut K> {
+        let (n, m) = self.size;
+        if i >= n || j >= m {
+            return None;
+        }
+        self.elements.get_mut(i + j * n)
+    }
+    // Reshape to n x m zeros, keeping the allocation when it is big enough
+    pub(crate) fn reset(&mut self, n: usize, m: usize) {
+        self.elements.clear();
+        self.elements.resize(n * m, K::default());
+        self.size = (n, m);
     }
     // Calculate the transpose of the matrix
     pub fn tr(&self) -> Result<Matrix<K>, LinAlgError> {
@@ -83,11 +121,11 @@ where
             m,
             n,
         )?;
-        for i in 1..=m {
-            for j in 1..=n {
-                to_return.set(i, j, self.el(j, i)?)?;
-            }
-        }
+        parallel::fill_with(
+            &mut to_return.elements,
+            m,
+            |i, j| self.elements[j + i * n],
+        );
         Ok(to_return)
     }
     // Calculate the adjunct of the matrix
@@ -123,35 +161,15 @@ where
     ) -> Result<Matrix<K>, LinAlgError> {
         Matrix::<K>::new(vec![K::one(); m * n], m, n)
     }
-    // Add two matrices of the same dimensions
-    pub fn add(
+    // Add two matrices (or views) of the same dimensions
+    pub fn add<M>(
         &self,
-        other: &Matrix<K>,
-    ) -> Result<Matrix<K>, LinAlgError> {
-        if self.size() != other.size() {
-            return Err(
-                LinAlgError::OperationNonConforming {
-                    operation: "Matrix Addition"
-                        .to_string(),
-                },
-            );
-        }
-        let (n, m) = self.size;
-        let mut to_return = Matrix::<K>::new(
-            vec![K::default(); m * n],
-            n,
-            m,
-        )?;
-        for i in 1..=n {
-            for j in 1..=m {
-                to_return.set(
-                    i,
-                    j,
-                    self.el(i, j)? + other.el(i, j)?,
-                )?;
-            }
-        }
-        Ok(to_return)
+        other: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.view().add(other)
     }
     // Scale the matrix by a factor
     pub fn scl(
@@ -160,15 +178,11 @@ where
     ) -> Result<Matrix<K>, LinAlgError> {
         let (n, m) = self.size;
         let mut to_return = Matrix::zero(n, m)?;
-        for i in 1..=n {
-            for j in 1..=m {
-                to_return.set(
-                    i,
-                    j,
-                    scaling * self.el(i, j)?,
-                )?;
-            }
-        }
+        parallel::fill_with(
+            &mut to_return.elements,
+            n,
+            |i, j| scaling * self.elements[i + j * n],
+        );
         Ok(to_return)
     }
     // Linearly interpolate between two matrices
@@ -182,35 +196,15 @@ where
             &[K::one() - t, t],
         )
     }
-    // Subtract one matrix from another of the same dimensions
-    pub fn sub(
+    // Subtract one matrix (or view) from another of the same dimensions
+    pub fn sub<M>(
         &self,
-        other: &Matrix<K>,
-    ) -> Result<Matrix<K>, LinAlgError> {
-        if self.size() != other.size() {
-            return Err(
-                LinAlgError::OperationNonConforming {
-                    operation: "Matrix Addition"
-                        .to_string(),
-                },
-            );
-        }
-        let (n, m) = self.size;
-        let mut to_return = Matrix::<K>::new(
-            vec![K::default(); m * n],
-            n,
-            m,
-        )?;
-        for i in 1..=n {
-            for j in 1..=m {
-                to_return.set(
-                    i,
-                    j,
-                    self.el(i, j)? - other.el(i, j)?,
-                )?;
-            }
-        }
-        Ok(to_return)
+        other: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.view().sub(other)
     }
     // Append another matrix horizontally to the current matrix
     pub fn append_horizontal(
@@ -226,7 +220,7 @@ where
                 },
             );
         }
-        Ok(Matrix::new(
+        Matrix::new(
             self.elements
                 .iter()
                 .cloned()
@@ -234,33 +228,17 @@ where
                 .collect(),
             n1,
             m1 + m2,
-        )?)
+        )
     }
-    // Multiply the matrix with another matrix
-    pub fn mlt(
+    // Multiply the matrix with another matrix (or view)
+    pub fn mlt<M>(
         &self,
-        other: &Matrix<K>,
-    ) -> Result<Matrix<K>, LinAlgError> {
-        let (n, p1) = self.size();
-        let (p2, m) = other.size();
-        if p1 != p2 {
-            return Err(LinAlgError::OperationNonConforming {
-                operation: "Sizes not conform to multiplication".to_string(),
-            });
-        };
-        let mut newv: Vec<K> =
-            (0..m * n).map(|_| K::default()).collect();
-        for i in 0..n {
-            for j in 0..m {
-                for w in 0..p1 {
-                    //println!("i {i} j {j} w {w} m {m} n {n}");
-                    newv[j * n + i] = newv[j * n + i]
-                        + self.el(i + 1, w + 1)?
-                            * other.el(w + 1, j + 1)?;
-                }
-            }
-        }
-        Matrix::<K>::new(newv, n, m)
+        other: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.view().mlt(other)
     }
     // Multiply the matrix with a vector
     pub fn mul_vec(
@@ -282,7 +260,7 @@ where
         u: &[&Matrix<K>],
         coefs: &[K],
     ) -> Result<Matrix<K>, LinAlgError> {
-        if !(u.len() != 0 && coefs.len() != 0) {
+        if u.is_empty() || coefs.is_empty() {
             return Err(LinAlgError::EmptyArgs);
         }
         if u.len() != coefs.len() {
@@ -296,7 +274,19 @@ where
         let (m, n) = u[0].size();
         let mut to_return = Matrix::<K>::zero(m, n)?;
         for (v, s) in u.iter().zip(coefs.iter()) {
-            to_return = to_return.add(&v.scl(*s)?)?;
+            if v.size() != (m, n) {
+                return Err(
+                    LinAlgError::OperationNonConforming {
+                        operation: "Matrix Addition"
+                            .to_string(),
+                    },
+                );
+            }
+            K::axpy(
+                *s,
+                &v.elements,
+                &mut to_return.elements,
+            );
         }
         Ok(to_return)
     }
@@ -319,7 +309,7 @@ where
         Ok(to_return)
     }
     // Scale a row of the matrix by a constant
-    fn row_scaling(
+    pub(crate) fn row_scaling(
         &mut self,
         row: usize,
         cnt: K,
@@ -337,7 +327,7 @@ where
         Ok(K::one() / cnt)
     }
     // Swap two rows of the matrix
-    fn row_swapping(
+    pub(crate) fn row_swapping(
         &mut self,
         row1: usize,
         row2: usize,
@@ -363,7 +353,7 @@ where
         Ok(K::default() - K::one())
     }
     // Add a scaled row to another row
-    fn row_static_add(
+    pub(crate) fn row_static_add(
         &mut self,
         dest: usize,
         org: usize,
@@ -392,103 +382,85 @@ where
         }
         Ok(K::one())
     }
-    // Perform Gaussian reduction to calculate determinant and rank, and optionally invert the matrix
-    fn gauss_red_det_rank(
-        &mut self,
-        inv: bool,
-    ) -> Result<(K, usize, Matrix<K>), LinAlgError> {
-        let (n, m) = self.size();
-        let mut det = K::one();
-        let mut pivot_row = 0;
-        let mut pivot_col = 0;
-
-        let mut inv_m;
-
-        if inv {
-            inv_m = Matrix::<K>::identity(n)?;
-        } else {
-            inv_m = Matrix::<K>::identity(1)?;
-        }
-
-        // in each column
-        'outer: for col in 1..=m {
-            if pivot_row >= n {
-                break;
-            }
-            //mare sure thar there is a pivot
-            if self.el(pivot_row + 1, col)? != K::default()
-            {
-                pivot_row += 1;
-                pivot_col = col;
-            } else {
-                // look for a new pivot
-                for row in (pivot_row + 1)..=n {
-                    if self.el(row, col)? != K::default() {
-                        if inv {
-                            _ = inv_m.row_swapping(
-                                row,
-                                pivot_row + 1,
-                            )?;
-                        }
-                        det = det
-                            * self.row_swapping(
-                                row,
-                                pivot_row + 1,
-                            )?;
-                        pivot_row += 1;
-                        pivot_col = col;
-                        break;
-                    }
-                    if row == n {
-                        // no pivot found
-                        det = K::default();
-                        if inv {
-                            break;
-                        } else {
-                            continue 'outer;
-                        }
-                    }
-                }
-            }
-            // Normalize the new pivot and reduce the column.
-            let scaling =
-                K::one() / self.el(pivot_row, pivot_col)?;
-            if inv {
-                _ = inv_m.row_scaling(pivot_row, scaling);
-            }
-            det = det
-                * self.row_scaling(pivot_row, scaling)?;
-            // Reduce all other rows
-            for row in 1..=n {
-                if row != pivot_row {
-                    if inv {
-                        _ = inv_m.row_static_add(
-                            row,
-                            pivot_row,
-                            K::default()
-                                - self.el(row, col)?,
-                        );
-                    }
-                    det = det
-                        * self.row_static_add(
-                            row,
-                            pivot_row,
-                            K::default()
-                                - self.el(row, col)?,
-                        )?;
-                }
-            }
-        }
-
-        Ok((det, pivot_row, inv_m))
+    // Factorize the matrix as P·A = L·U using partial pivoting
+    pub fn lu(
+        &self,
+    ) -> Result<LuDecomposition<K>, LinAlgError> {
+        LuDecomposition::new(self)
+    }
+    // Factorize the matrix as A = Q·R with Householder reflections
+    pub fn qr(
+        &self,
+    ) -> Result<QrDecomposition<K>, LinAlgError> {
+        QrDecomposition::new(self, false)
     }
-    // Convert the matrix to row echelon form
+    // Economy-size QR: Q keeps only min(m, n) columns
+    pub fn qr_economy(
+        &self,
+    ) -> Result<QrDecomposition<K>, LinAlgError> {
+        QrDecomposition::new(self, true)
+    }
+    // Factorize a Hermitian positive-definite matrix as A = L·Lᴴ
+    pub fn cholesky(
+        &self,
+    ) -> Result<CholeskyDecomposition<K>, LinAlgError> {
+        CholeskyDecomposition::new(self)
+    }
+    // Eigenvalues and eigenvectors of a symmetric or Hermitian matrix
+    pub fn eigh(
+        &self,
+    ) -> Result<SymmetricEigen<K>, LinAlgError> {
+        SymmetricEigen::new(self)
+    }
+    // Singular value decomposition A = U·Σ·Vᴴ
+    pub fn svd(
+        &self,
+    ) -> Result<SingularValueDecomposition<K>, LinAlgError>
+    {
+        SingularValueDecomposition::new(self)
+    }
+    // Moore-Penrose pseudo-inverse
+    pub fn pinv(&self) -> Result<Matrix<K>, LinAlgError> {
+        self.svd()?.pinv()
+    }
+    // Number of singular values above `tol`
+    pub fn numerical_rank(
+        &self,
+        tol: f64,
+    ) -> Result<usize, LinAlgError> {
+        self.svd()?.numerical_rank(tol)
+    }
+    // Condition number in the 2-norm
+    pub fn cond(&self) -> Result<f64, LinAlgError> {
+        self.svd()?.cond()
+    }
+    // Spectral norm: the largest singular value
+    pub fn norm_2(&self) -> Result<f64, LinAlgError> {
+        self.svd()?.norm_2()
+    }
+    // Convert the matrix to reduced row echelon form
     pub fn row_echelon(
         &self,
     ) -> Result<Matrix<K>, LinAlgError> {
-        let mut to_return = self.clone();
-        let (_, _, _) =
-            to_return.gauss_red_det_rank(false)?;
+        let lu = self.lu()?;
+        let mut to_return = lu.u().clone();
+        for (row, &col) in
+            lu.pivot_columns().iter().enumerate()
+        {
+            let row = row + 1;
+            let scaling =
+                K::one() / to_return.el(row, col)?;
+            to_return.row_scaling(row, scaling)?;
+            to_return.set(row, col, K::one())?;
+            // Clear the column above the pivot
+            for other in 1..row {
+                let factor = K::default()
+                    - to_return.el(other, col)?;
+                to_return
+                    .row_static_add(other, row, factor)?;
+                to_return.set(other, col, K::default())?;
+            }
+        }
         Ok(to_return)
     }
     // Calculate the determinant of the matrix
@@ -500,17 +472,11 @@ where
                     .to_string(),
             });
         }
-        let mut to_return = self.clone();
-        let (det, _, _) =
-            to_return.gauss_red_det_rank(false)?;
-        Ok(det)
+        self.lu()?.determinant()
     }
     // Calculate the rank of the matrix
     pub fn rank(&self) -> Result<usize, LinAlgError> {
-        let mut to_return = self.clone();
-        let (_, rank, _) =
-            to_return.gauss_red_det_rank(false)?;
-        Ok(rank)
+        Ok(self.lu()?.rank())
     }
     // Create a diagonal matrix from a vector
     pub fn diag(
@@ -543,13 +509,38 @@ where
                     .to_string(),
             });
         }
-        let mut to_alg = self.clone();
-        let (_, rank, inv) =
-            to_alg.gauss_red_det_rank(true)?;
-        if rank != n {
-            return Err(LinAlgError::SinglarMatrix);
-        };
-        return Ok(inv);
+        self.lu()?.inverse()
+    }
+    // Solve the linear system A·x = b
+    pub fn solve(
+        &self,
+        b: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        Vector::new(self.solve_mat(b.matrix())?.elements)
+    }
+    // Solve A·X = B for every column of B at once
+    pub fn solve_mat<M>(
+        &self,
+        b: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        let (m, n) = self.size();
+        if m != n {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Not a square matrix. Can't solve the system."
+                    .to_string(),
+            });
+        }
+        self.lu()?.solve_mat(&b.view().to_matrix()?)
+    }
+    // Find x minimizing ‖A·x − b‖₂ for tall or rank deficient systems
+    pub fn lstsq(
+        &self,
+        b: &Vector<K>,
+    ) -> Result<LstsqSolution<K>, LinAlgError> {
+        lstsq(self, b)
     }
     // Generate a projection matrix with given field of view, aspect ratio, and near/far planes
     pub fn projection(
@@ -573,15 +564,13 @@ where
         col: usize,
     ) -> Result<Vector<K>, LinAlgError> {
         let (_, cols) = self.size();
-        if col > cols {
+        if col == 0 || col > cols {
             return Err(LinAlgError::OutofBoundsVector {
                 size: cols,
                 recieved: col,
             });
         }
-        let mut mv = Vector::<K>::zero(cols)?;
-        mv.set(col, K::one())?;
-        self.mul_vec(&mv)
+        self.col(col)?.to_vector()
     }
 }
 // Specialize `Matrix<f64>` for operations involving normalization
@@ -601,6 +590,24 @@ impl Matrix<f64> {
         }
         Ok((to_ret, coefs))
     }
+    // Eigenvalues of a general square matrix, complex in general; conjugate
+    // pairs come positive imaginary part first
+    pub fn eig(
+        &self,
+    ) -> Result<Vector<ComplexNumber>, LinAlgError> {
+        eig::eigenvalues(self)
+    }
+    // Eigenvalues together with the right eigenvectors as columns
+    pub fn eig_vectors(
+        &self,
+    ) -> Result<
+        (Vector<ComplexNumber>, Matrix<ComplexNumber>),
+        LinAlgError,
+    > {
+        let values = eig::eigenvalues(self)?;
+        let vectors = eig::eigenvectors(self, &values)?;
+        Ok((values, vectors))
+    }
     // Helper function to normalize a single column
     fn normalize_col(
         &mut self,
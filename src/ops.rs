@@ -0,0 +1,230 @@
+// Operator overloading for `Matrix` and `Vector` on top of the fallible methods
+use std::ops::{
+    Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg,
+    Sub, SubAssign,
+};
+
+use crate::{
+    complex::ComplexNumber, errors::LinAlgError,
+    matrix::Matrix, traits::Field, vector::Vector,
+};
+
+// Operators can't return a `Result`, so dimension errors become panics
+fn or_panic<T>(result: Result<T, LinAlgError>) -> T {
+    result.unwrap_or_else(|e| panic!("{}", e))
+}
+
+// Implement a binary operator for every owned/borrowed combination
+macro_rules! binop {
+    ($tr:ident, $method:ident, $lhs:ident, $rhs:ident, $out:ident, $f:expr) => {
+        impl<K> $tr<&$rhs<K>> for &$lhs<K>
+        where
+            K: Field,
+        {
+            type Output = $out<K>;
+            fn $method(self, other: &$rhs<K>) -> $out<K> {
+                or_panic($f(self, other))
+            }
+        }
+        impl<K> $tr<$rhs<K>> for &$lhs<K>
+        where
+            K: Field,
+        {
+            type Output = $out<K>;
+            fn $method(self, other: $rhs<K>) -> $out<K> {
+                or_panic($f(self, &other))
+            }
+        }
+        impl<K> $tr<&$rhs<K>> for $lhs<K>
+        where
+            K: Field,
+        {
+            type Output = $out<K>;
+            fn $method(self, other: &$rhs<K>) -> $out<K> {
+                or_panic($f(&self, other))
+            }
+        }
+        impl<K> $tr<$rhs<K>> for $lhs<K>
+        where
+            K: Field,
+        {
+            type Output = $out<K>;
+            fn $method(self, other: $rhs<K>) -> $out<K> {
+                or_panic($f(&self, &other))
+            }
+        }
+    };
+}
+
+binop!(Add, add, Matrix, Matrix, Matrix, Matrix::add);
+binop!(Sub, sub, Matrix, Matrix, Matrix, Matrix::sub);
+binop!(Mul, mul, Matrix, Matrix, Matrix, Matrix::mlt);
+binop!(Mul, mul, Matrix, Vector, Vector, Matrix::mul_vec);
+binop!(Add, add, Vector, Vector, Vector, Vector::add);
+binop!(Sub, sub, Vector, Vector, Vector, Vector::sub);
+
+// Scaling, unary minus and compound assignment for both types
+macro_rules! scalar_ops {
+    ($t:ident) => {
+        impl<K> Mul<K> for &$t<K>
+        where
+            K: Field,
+        {
+            type Output = $t<K>;
+            fn mul(self, scaling: K) -> $t<K> {
+                or_panic($t::scl(self, scaling))
+            }
+        }
+        impl<K> Mul<K> for $t<K>
+        where
+            K: Field,
+        {
+            type Output = $t<K>;
+            fn mul(self, scaling: K) -> $t<K> {
+                or_panic($t::scl(&self, scaling))
+            }
+        }
+        impl<K> Neg for &$t<K>
+        where
+            K: Field,
+        {
+            type Output = $t<K>;
+            fn neg(self) -> $t<K> {
+                or_panic($t::scl(
+                    self,
+                    K::default() - K::one(),
+                ))
+            }
+        }
+        impl<K> Neg for $t<K>
+        where
+            K: Field,
+        {
+            type Output = $t<K>;
+            fn neg(self) -> $t<K> {
+                -&self
+            }
+        }
+        impl<K> AddAssign<&$t<K>> for $t<K>
+        where
+            K: Field,
+        {
+            fn add_assign(&mut self, other: &$t<K>) {
+                or_panic($t::add_assign(self, other));
+            }
+        }
+        impl<K> AddAssign<$t<K>> for $t<K>
+        where
+            K: Field,
+        {
+            fn add_assign(&mut self, other: $t<K>) {
+                *self += &other;
+            }
+        }
+        impl<K> SubAssign<&$t<K>> for $t<K>
+        where
+            K: Field,
+        {
+            fn sub_assign(&mut self, other: &$t<K>) {
+                or_panic($t::sub_assign(self, other));
+            }
+        }
+        impl<K> SubAssign<$t<K>> for $t<K>
+        where
+            K: Field,
+        {
+            fn sub_assign(&mut self, other: $t<K>) {
+                *self -= &other;
+            }
+        }
+        impl<K> MulAssign<K> for $t<K>
+        where
+            K: Field,
+        {
+            fn mul_assign(&mut self, scaling: K) {
+                or_panic($t::scale_mut(self, scaling));
+            }
+        }
+    };
+}
+
+scalar_ops!(Matrix);
+scalar_ops!(Vector);
+
+// A scalar on the left needs one impl per concrete field
+macro_rules! left_scalar {
+    ($k:ty) => {
+        impl Mul<&Matrix<$k>> for $k {
+            type Output = Matrix<$k>;
+            fn mul(self, m: &Matrix<$k>) -> Matrix<$k> {
+                m * self
+            }
+        }
+        impl Mul<Matrix<$k>> for $k {
+            type Output = Matrix<$k>;
+            fn mul(self, m: Matrix<$k>) -> Matrix<$k> {
+                m * self
+            }
+        }
+        impl Mul<&Vector<$k>> for $k {
+            type Output = Vector<$k>;
+            fn mul(self, v: &Vector<$k>) -> Vector<$k> {
+                v * self
+            }
+        }
+        impl Mul<Vector<$k>> for $k {
+            type Output = Vector<$k>;
+            fn mul(self, v: Vector<$k>) -> Vector<$k> {
+                v * self
+            }
+        }
+    };
+}
+
+left_scalar!(f32);
+left_scalar!(f64);
+left_scalar!(ComplexNumber);
+
+// 1-based indexing, panicking like `el` would fail
+impl<K> Index<(usize, usize)> for Matrix<K>
+where
+    K: Field,
+{
+    type Output = K;
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        &self.elements[or_panic(self.offset(i, j))]
+    }
+}
+
+impl<K> IndexMut<(usize, usize)> for Matrix<K>
+where
+    K: Field,
+{
+    fn index_mut(
+        &mut self,
+        (i, j): (usize, usize),
+    ) -> &mut K {
+        let offset = or_panic(self.offset(i, j));
+        &mut self.elements[offset]
+    }
+}
+
+impl<K> Index<usize> for Vector<K>
+where
+    K: Field,
+{
+    type Output = K;
+    fn index(&self, i: usize) -> &K {
+        &self.matrix.elements[or_panic(self.offset(i))]
+    }
+}
+
+impl<K> IndexMut<usize> for Vector<K>
+where
+    K: Field,
+{
+    fn index_mut(&mut self, i: usize) -> &mut K {
+        let offset = or_panic(self.offset(i));
+        &mut self.matrix.elements[offset]
+    }
+}
@@ -0,0 +1,106 @@
+// Optional multithreading: work is split into blocks of whole columns and
+// run on scoped threads when the `parallel` feature is enabled
+#[cfg(feature = "parallel")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Zero means one thread per available core
+#[cfg(feature = "parallel")]
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+// Operations with fewer scalar steps than this stay on the calling thread
+#[cfg(feature = "parallel")]
+static THRESHOLD: AtomicUsize = AtomicUsize::new(1 << 18);
+
+// Set the number of threads to use, zero for one per available core
+#[cfg(feature = "parallel")]
+pub fn set_num_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+// Number of threads a large operation is split across
+#[cfg(feature = "parallel")]
+pub fn num_threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+        n => n,
+    }
+}
+
+// Set the amount of scalar work below which operations stay serial
+#[cfg(feature = "parallel")]
+pub fn set_threshold(work: usize) {
+    THRESHOLD.store(work, Ordering::Relaxed);
+}
+
+// Amount of scalar work below which operations stay serial
+#[cfg(feature = "parallel")]
+pub fn threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "parallel")]
+fn threads_for(work: usize) -> usize {
+    if work < threshold() {
+        1
+    } else {
+        num_threads()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn threads_for(_work: usize) -> usize {
+    1
+}
+
+// Run `f(first_column, block)` over blocks of whole columns of a
+// column-major buffer with `rows` rows, where `work` estimates the cost
+pub(crate) fn for_columns<K, F>(
+    data: &mut [K],
+    rows: usize,
+    work: usize,
+    f: F,
+) where
+    K: Send,
+    F: Fn(usize, &mut [K]) + Sync,
+{
+    if data.is_empty() || rows == 0 {
+        return;
+    }
+    let cols = data.len() / rows;
+    let threads = usize::min(threads_for(work), cols);
+    if threads <= 1 {
+        f(0, data);
+        return;
+    }
+    let per_thread = cols.div_ceil(threads);
+    std::thread::scope(|s| {
+        for (t, block) in
+            data.chunks_mut(per_thread * rows).enumerate()
+        {
+            let f = &f;
+            s.spawn(move || f(t * per_thread, block));
+        }
+    });
+}
+
+// Set every entry of a column-major buffer to `f(i, j)`, 0-based
+pub(crate) fn fill_with<K, F>(
+    data: &mut [K],
+    rows: usize,
+    f: F,
+) where
+    K: Send,
+    F: Fn(usize, usize) -> K + Sync,
+{
+    let work = data.len();
+    for_columns(data, rows, work, |first, block| {
+        for (j, column) in
+            block.chunks_mut(rows).enumerate()
+        {
+            for (i, x) in column.iter_mut().enumerate() {
+                *x = f(i, first + j);
+            }
+        }
+    });
+}
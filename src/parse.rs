@@ -0,0 +1,262 @@
+use crate::{
+    complex::ComplexNumber, errors::LinAlgError,
+    matrix::Matrix, traits::Field, vector::Vector,
+};
+use std::str::FromStr;
+
+// Walks through the text written by `Display`, remembering where it is so
+// errors can point at the offending token
+struct Cursor<'a> {
+    text: &'a str,
+    at: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Cursor<'a> {
+        Cursor { text, at: 0 }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.at..];
+        self.at += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.text[self.at..].chars().next()
+    }
+
+    // The token starting at `at`: a single bracket or comma, or everything
+    // up to the next one
+    fn token(&self, at: usize) -> &'a str {
+        let rest = &self.text[at..];
+        match rest.chars().next() {
+            None => "",
+            Some(c) if "[],".contains(c) => &rest[..1],
+            Some(_) => {
+                let end = rest
+                    .find(|c| "[],".contains(c))
+                    .unwrap_or(rest.len());
+                rest[..end].trim_end()
+            }
+        }
+    }
+
+    // 1-based line and column of a byte offset
+    fn location(&self, at: usize) -> (usize, usize) {
+        let before = &self.text[..at];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            [before.rfind('\n').map_or(0, |k| k + 1)..]
+            .chars()
+            .count()
+            + 1;
+        (line, column)
+    }
+
+    fn error(
+        &self,
+        at: usize,
+        message: &str,
+    ) -> LinAlgError {
+        let (line, column) = self.location(at);
+        let found = match self.token(at) {
+            "" => "end of input".to_string(),
+            token => format!("{:?}", token),
+        };
+        LinAlgError::Parse {
+            line,
+            message: format!(
+                "{} at column {}, found {}",
+                message, column, found
+            ),
+        }
+    }
+
+    fn expect(
+        &mut self,
+        c: char,
+    ) -> Result<(), LinAlgError> {
+        if self.peek() != Some(c) {
+            return Err(self.error(
+                self.at,
+                &format!("expected '{}'", c),
+            ));
+        }
+        self.at += 1;
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<(), LinAlgError> {
+        if self.peek().is_some() {
+            return Err(self
+                .error(self.at, "expected end of input"));
+        }
+        Ok(())
+    }
+
+    fn element<K>(&mut self) -> Result<K, LinAlgError>
+    where
+        K: FromStr,
+    {
+        self.skip_whitespace();
+        let token = self.token(self.at);
+        if token.is_empty() || token == "," || token == "]"
+        {
+            return Err(
+                self.error(self.at, "expected a number")
+            );
+        }
+        if token == "[" {
+            return Err(
+                self.error(self.at, "unexpected '['")
+            );
+        }
+        match token.parse::<K>() {
+            Ok(x) => {
+                self.at += token.len();
+                Ok(x)
+            }
+            Err(_) => {
+                Err(self.error(self.at, "invalid number"))
+            }
+        }
+    }
+
+    // [a,b,c], possibly empty
+    fn list<K>(&mut self) -> Result<Vec<K>, LinAlgError>
+    where
+        K: FromStr,
+    {
+        self.expect('[')?;
+        let mut to_return = Vec::new();
+        if self.peek() == Some(']') {
+            self.at += 1;
+            return Ok(to_return);
+        }
+        loop {
+            to_return.push(self.element()?);
+            match self.peek() {
+                Some(',') => self.at += 1,
+                Some(']') => {
+                    self.at += 1;
+                    return Ok(to_return);
+                }
+                _ => {
+                    return Err(self.error(
+                        self.at,
+                        "expected ',' or ']'",
+                    ))
+                }
+            }
+        }
+    }
+}
+
+// Reads `re + imi` or `Inf`, as printed by `Display`
+impl FromStr for ComplexNumber {
+    type Err = LinAlgError;
+
+    fn from_str(
+        s: &str,
+    ) -> Result<ComplexNumber, LinAlgError> {
+        let trimmed = s.trim();
+        if trimmed == "Inf" {
+            return Ok(ComplexNumber::Infinity);
+        }
+        let parts = trimmed
+            .strip_suffix('i')
+            .and_then(|x| x.split_once(" + "));
+        if let Some((re, im)) = parts {
+            if let (Ok(re), Ok(im)) = (
+                re.trim().parse::<f64>(),
+                im.trim().parse::<f64>(),
+            ) {
+                return Ok(ComplexNumber::n(re, im));
+            }
+        }
+        Err(LinAlgError::Parse {
+            line: 1,
+            message: format!(
+                "invalid complex number {:?}",
+                trimmed
+            ),
+        })
+    }
+}
+
+// Reads `[a,b,c]`, as printed by `Display`
+impl<K> FromStr for Vector<K>
+where
+    K: Field + FromStr,
+{
+    type Err = LinAlgError;
+
+    fn from_str(s: &str) -> Result<Vector<K>, LinAlgError> {
+        let mut cursor = Cursor::new(s);
+        let elements = cursor.list()?;
+        cursor.end()?;
+        Vector::new(elements)
+    }
+}
+
+// Reads `[[a,b],[c,d]]`, one bracket per column, as printed by `Display`.
+// A matrix without columns prints as `[]` whatever its row count, so `[]`
+// is rejected rather than guessed; 0 x m matrices print as `[[],[]]` and
+// read back fine.
+impl<K> FromStr for Matrix<K>
+where
+    K: Field + FromStr,
+{
+    type Err = LinAlgError;
+
+    fn from_str(s: &str) -> Result<Matrix<K>, LinAlgError> {
+        let mut cursor = Cursor::new(s);
+        cursor.expect('[')?;
+        let mut elements = Vec::new();
+        let mut rows = None;
+        let mut cols = 0;
+        if cursor.peek() == Some(']') {
+            return Err(cursor.error(
+                cursor.at,
+                "expected a column, the row count of a matrix without columns is unknown",
+            ));
+        }
+        loop {
+            cursor.skip_whitespace();
+            let start = cursor.at;
+            let column: Vec<K> = cursor.list()?;
+            let expected =
+                *rows.get_or_insert(column.len());
+            if column.len() != expected {
+                let (line, at) = cursor.location(start);
+                return Err(LinAlgError::Parse {
+                    line,
+                    message: format!(
+                        "expected a column of {} entries got {} at column {}",
+                        expected,
+                        column.len(),
+                        at
+                    ),
+                });
+            }
+            elements.extend(column);
+            cols += 1;
+            match cursor.peek() {
+                Some(',') => cursor.at += 1,
+                Some(']') => {
+                    cursor.at += 1;
+                    break;
+                }
+                _ => {
+                    return Err(cursor.error(
+                        cursor.at,
+                        "expected ',' or ']'",
+                    ))
+                }
+            }
+        }
+        cursor.end()?;
+        Matrix::new(elements, rows.unwrap_or(0), cols)
+    }
+}
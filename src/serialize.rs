@@ -0,0 +1,104 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, traits::Field,
+    vector::Vector,
+};
+use serde::{
+    de::Error, Deserialize, Deserializer, Serialize,
+    Serializer,
+};
+
+// A matrix is stored as its shape and the column-major `elements`
+#[derive(Serialize)]
+#[serde(rename = "Matrix")]
+struct MatrixRef<'a, K> {
+    rows: usize,
+    cols: usize,
+    elements: &'a [K],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct MatrixData<K> {
+    rows: usize,
+    cols: usize,
+    elements: Vec<K>,
+}
+
+impl<K> Serialize for Matrix<K>
+where
+    K: Field + Serialize,
+{
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (rows, cols) = self.size();
+        MatrixRef {
+            rows,
+            cols,
+            elements: &self.elements,
+        }
+        .serialize(serializer)
+    }
+}
+
+// Loading goes through `Matrix::new`, so a shape that doesn't match the
+// data fails like any other build; a shape whose size overflows can't match
+// any data
+impl<'de, K> Deserialize<'de> for Matrix<K>
+where
+    K: Field + Deserialize<'de>,
+{
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<Matrix<K>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = MatrixData::deserialize(deserializer)?;
+        if data.rows.checked_mul(data.cols).is_none() {
+            return Err(D::Error::custom(
+                LinAlgError::BuildNonconforming {
+                    expected: usize::MAX,
+                    recieved: data.elements.len(),
+                },
+            ));
+        }
+        Matrix::new(data.elements, data.rows, data.cols)
+            .map_err(D::Error::custom)
+    }
+}
+
+// A vector is stored as the plain list of its entries
+impl<K> Serialize for Vector<K>
+where
+    K: Field + Serialize,
+{
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.matrix.elements.serialize(serializer)
+    }
+}
+
+impl<'de, K> Deserialize<'de> for Vector<K>
+where
+    K: Field + Deserialize<'de>,
+{
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> Result<Vector<K>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let elements = Vec::<K>::deserialize(deserializer)?;
+        Vector::new(elements).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,573 @@
+// Vectorized f32 and f64 kernels for x86_64. AVX2 and FMA are detected at
+// runtime, once; SSE2 is always present on x86_64 and serves as the
+// fallback.
+use std::arch::x86_64::*;
+use std::sync::OnceLock;
+
+// The implementation of each kernel for the running CPU
+struct Dispatch {
+    dot_f64: unsafe fn(&[f64], &[f64]) -> f64,
+    dot_f32: unsafe fn(&[f32], &[f32]) -> f32,
+    axpy_f64: unsafe fn(f64, &[f64], &mut [f64]),
+    axpy_f32: unsafe fn(f32, &[f32], &mut [f32]),
+    asum_f64: unsafe fn(&[f64]) -> f64,
+    asum_f32: unsafe fn(&[f32]) -> f64,
+    amax_f64: unsafe fn(&[f64]) -> f64,
+    tile_f64: unsafe fn(&[f64], &[f64], &mut [[f64; 4]; 4]),
+}
+
+static DISPATCH: OnceLock<Dispatch> = OnceLock::new();
+
+// Detect the CPU features on first use and keep the choice
+fn dispatch() -> &'static Dispatch {
+    DISPATCH.get_or_init(|| {
+        let fast = is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma");
+        let avx = is_x86_feature_detected!("avx");
+        Dispatch {
+            dot_f64: if fast {
+                dot_f64_avx2
+            } else {
+                dot_f64_sse2
+            },
+            dot_f32: if fast {
+                dot_f32_avx2
+            } else {
+                dot_f32_sse2
+            },
+            axpy_f64: if fast {
+                axpy_f64_avx2
+            } else {
+                axpy_f64_sse2
+            },
+            axpy_f32: if fast {
+                axpy_f32_avx2
+            } else {
+                axpy_f32_sse2
+            },
+            asum_f64: if fast {
+                asum_f64_avx2
+            } else {
+                asum_f64_sse2
+            },
+            asum_f32: if fast {
+                asum_f32_avx2
+            } else {
+                asum_f32_sse2
+            },
+            amax_f64: if fast {
+                amax_f64_avx2
+            } else {
+                amax_f64_sse2
+            },
+            tile_f64: if avx {
+                tile_f64_avx
+            } else {
+                tile_f64_sse2
+            },
+        }
+    })
+}
+
+// Σ xᵢ·yᵢ
+pub(crate) fn dot_f64(x: &[f64], y: &[f64]) -> f64 {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().dot_f64)(x, y) }
+}
+
+pub(crate) fn dot_f32(x: &[f32], y: &[f32]) -> f32 {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().dot_f32)(x, y) }
+}
+
+// y ← a·x + y, rounding the product first so results match the scalar loop
+pub(crate) fn axpy_f64(a: f64, x: &[f64], y: &mut [f64]) {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().axpy_f64)(a, x, y) }
+}
+
+pub(crate) fn axpy_f32(a: f32, x: &[f32], y: &mut [f32]) {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().axpy_f32)(a, x, y) }
+}
+
+// Σ |xᵢ|
+pub(crate) fn asum_f64(x: &[f64]) -> f64 {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().asum_f64)(x) }
+}
+
+// Σ |xᵢ|, accumulated in f64
+pub(crate) fn asum_f32(x: &[f32]) -> f64 {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().asum_f32)(x) }
+}
+
+// max |xᵢ|, skipping NaNs like the scalar loop does
+pub(crate) fn amax_f64(x: &[f64]) -> f64 {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().amax_f64)(x) }
+}
+
+pub(crate) fn amax_f32(x: &[f32]) -> f64 {
+    // SAFETY: SSE2 is part of the x86_64 baseline
+    unsafe { amax_f32_sse2(x) as f64 }
+}
+
+// GEMM register tile: acc[j][i] += a[4p + i]·b[4p + j] for every p.
+// Only i and j are vectorized and products are rounded before the sum,
+// so the result is bit for bit the one of the scalar loop.
+pub(crate) fn tile_f64(
+    a: &[f64],
+    b: &[f64],
+    acc: &mut [[f64; 4]; 4],
+) {
+    // SAFETY: `dispatch` only picks kernels the CPU supports
+    unsafe { (dispatch().tile_f64)(a, b, acc) }
+}
+
+pub(crate) fn tile_f32(
+    a: &[f32],
+    b: &[f32],
+    acc: &mut [[f32; 4]; 4],
+) {
+    // SAFETY: SSE2 is part of the x86_64 baseline
+    unsafe { tile_f32_sse2(a, b, acc) }
+}
+
+fn hsum_f64(lanes: &[f64]) -> f64 {
+    lanes.iter().sum()
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dot_f64_avx2(x: &[f64], y: &[f64]) -> f64 {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc0 = _mm256_setzero_pd();
+    let mut acc1 = _mm256_setzero_pd();
+    let mut i = 0;
+    while i + 8 <= n {
+        acc0 = _mm256_fmadd_pd(
+            _mm256_loadu_pd(px.add(i)),
+            _mm256_loadu_pd(py.add(i)),
+            acc0,
+        );
+        acc1 = _mm256_fmadd_pd(
+            _mm256_loadu_pd(px.add(i + 4)),
+            _mm256_loadu_pd(py.add(i + 4)),
+            acc1,
+        );
+        i += 8;
+    }
+    let mut lanes = [0.0; 4];
+    _mm256_storeu_pd(
+        lanes.as_mut_ptr(),
+        _mm256_add_pd(acc0, acc1),
+    );
+    let mut sum = hsum_f64(&lanes);
+    for (a, b) in x[i..n].iter().zip(&y[i..n]) {
+        sum += a * b;
+    }
+    sum
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn dot_f64_sse2(x: &[f64], y: &[f64]) -> f64 {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc0 = _mm_setzero_pd();
+    let mut acc1 = _mm_setzero_pd();
+    let mut i = 0;
+    while i + 4 <= n {
+        acc0 = _mm_add_pd(
+            acc0,
+            _mm_mul_pd(
+                _mm_loadu_pd(px.add(i)),
+                _mm_loadu_pd(py.add(i)),
+            ),
+        );
+        acc1 = _mm_add_pd(
+            acc1,
+            _mm_mul_pd(
+                _mm_loadu_pd(px.add(i + 2)),
+                _mm_loadu_pd(py.add(i + 2)),
+            ),
+        );
+        i += 4;
+    }
+    let mut lanes = [0.0; 2];
+    _mm_storeu_pd(
+        lanes.as_mut_ptr(),
+        _mm_add_pd(acc0, acc1),
+    );
+    let mut sum = hsum_f64(&lanes);
+    for (a, b) in x[i..n].iter().zip(&y[i..n]) {
+        sum += a * b;
+    }
+    sum
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dot_f32_avx2(x: &[f32], y: &[f32]) -> f32 {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc0 = _mm256_setzero_ps();
+    let mut acc1 = _mm256_setzero_ps();
+    let mut i = 0;
+    while i + 16 <= n {
+        acc0 = _mm256_fmadd_ps(
+            _mm256_loadu_ps(px.add(i)),
+            _mm256_loadu_ps(py.add(i)),
+            acc0,
+        );
+        acc1 = _mm256_fmadd_ps(
+            _mm256_loadu_ps(px.add(i + 8)),
+            _mm256_loadu_ps(py.add(i + 8)),
+            acc1,
+        );
+        i += 16;
+    }
+    let mut lanes = [0.0; 8];
+    _mm256_storeu_ps(
+        lanes.as_mut_ptr(),
+        _mm256_add_ps(acc0, acc1),
+    );
+    let mut sum: f32 = lanes.iter().sum();
+    for (a, b) in x[i..n].iter().zip(&y[i..n]) {
+        sum += a * b;
+    }
+    sum
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn dot_f32_sse2(x: &[f32], y: &[f32]) -> f32 {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_ptr());
+    let mut acc0 = _mm_setzero_ps();
+    let mut acc1 = _mm_setzero_ps();
+    let mut i = 0;
+    while i + 8 <= n {
+        acc0 = _mm_add_ps(
+            acc0,
+            _mm_mul_ps(
+                _mm_loadu_ps(px.add(i)),
+                _mm_loadu_ps(py.add(i)),
+            ),
+        );
+        acc1 = _mm_add_ps(
+            acc1,
+            _mm_mul_ps(
+                _mm_loadu_ps(px.add(i + 4)),
+                _mm_loadu_ps(py.add(i + 4)),
+            ),
+        );
+        i += 8;
+    }
+    let mut lanes = [0.0; 4];
+    _mm_storeu_ps(
+        lanes.as_mut_ptr(),
+        _mm_add_ps(acc0, acc1),
+    );
+    let mut sum: f32 = lanes.iter().sum();
+    for (a, b) in x[i..n].iter().zip(&y[i..n]) {
+        sum += a * b;
+    }
+    sum
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn axpy_f64_avx2(a: f64, x: &[f64], y: &mut [f64]) {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm256_set1_pd(a);
+    let mut i = 0;
+    while i + 4 <= n {
+        let v = _mm256_add_pd(
+            _mm256_loadu_pd(py.add(i)),
+            _mm256_mul_pd(va, _mm256_loadu_pd(px.add(i))),
+        );
+        _mm256_storeu_pd(py.add(i), v);
+        i += 4;
+    }
+    for (xi, yi) in x[i..n].iter().zip(&mut y[i..n]) {
+        *yi += a * xi;
+    }
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn axpy_f64_sse2(a: f64, x: &[f64], y: &mut [f64]) {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm_set1_pd(a);
+    let mut i = 0;
+    while i + 2 <= n {
+        let v = _mm_add_pd(
+            _mm_loadu_pd(py.add(i)),
+            _mm_mul_pd(va, _mm_loadu_pd(px.add(i))),
+        );
+        _mm_storeu_pd(py.add(i), v);
+        i += 2;
+    }
+    for (xi, yi) in x[i..n].iter().zip(&mut y[i..n]) {
+        *yi += a * xi;
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn axpy_f32_avx2(a: f32, x: &[f32], y: &mut [f32]) {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm256_set1_ps(a);
+    let mut i = 0;
+    while i + 8 <= n {
+        let v = _mm256_add_ps(
+            _mm256_loadu_ps(py.add(i)),
+            _mm256_mul_ps(va, _mm256_loadu_ps(px.add(i))),
+        );
+        _mm256_storeu_ps(py.add(i), v);
+        i += 8;
+    }
+    for (xi, yi) in x[i..n].iter().zip(&mut y[i..n]) {
+        *yi += a * xi;
+    }
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn axpy_f32_sse2(a: f32, x: &[f32], y: &mut [f32]) {
+    let n = usize::min(x.len(), y.len());
+    let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+    let va = _mm_set1_ps(a);
+    let mut i = 0;
+    while i + 4 <= n {
+        let v = _mm_add_ps(
+            _mm_loadu_ps(py.add(i)),
+            _mm_mul_ps(va, _mm_loadu_ps(px.add(i))),
+        );
+        _mm_storeu_ps(py.add(i), v);
+        i += 4;
+    }
+    for (xi, yi) in x[i..n].iter().zip(&mut y[i..n]) {
+        *yi += a * xi;
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn asum_f64_avx2(x: &[f64]) -> f64 {
+    let px = x.as_ptr();
+    let sign = _mm256_set1_pd(-0.0);
+    let mut acc = _mm256_setzero_pd();
+    let mut i = 0;
+    while i + 4 <= x.len() {
+        let v = _mm256_andnot_pd(
+            sign,
+            _mm256_loadu_pd(px.add(i)),
+        );
+        acc = _mm256_add_pd(acc, v);
+        i += 4;
+    }
+    let mut lanes = [0.0; 4];
+    _mm256_storeu_pd(lanes.as_mut_ptr(), acc);
+    hsum_f64(&lanes)
+        + x[i..].iter().map(|v| v.abs()).sum::<f64>()
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn asum_f64_sse2(x: &[f64]) -> f64 {
+    let px = x.as_ptr();
+    let sign = _mm_set1_pd(-0.0);
+    let mut acc = _mm_setzero_pd();
+    let mut i = 0;
+    while i + 2 <= x.len() {
+        let v =
+            _mm_andnot_pd(sign, _mm_loadu_pd(px.add(i)));
+        acc = _mm_add_pd(acc, v);
+        i += 2;
+    }
+    let mut lanes = [0.0; 2];
+    _mm_storeu_pd(lanes.as_mut_ptr(), acc);
+    hsum_f64(&lanes)
+        + x[i..].iter().map(|v| v.abs()).sum::<f64>()
+}
+
+#[target_feature(enable = "avx2,fma")]
+unsafe fn asum_f32_avx2(x: &[f32]) -> f64 {
+    let px = x.as_ptr();
+    let sign = _mm256_set1_pd(-0.0);
+    let mut acc = _mm256_setzero_pd();
+    let mut i = 0;
+    while i + 4 <= x.len() {
+        let v = _mm256_cvtps_pd(_mm_loadu_ps(px.add(i)));
+        acc = _mm256_add_pd(acc, _mm256_andnot_pd(sign, v));
+        i += 4;
+    }
+    let mut lanes = [0.0; 4];
+    _mm256_storeu_pd(lanes.as_mut_ptr(), acc);
+    hsum_f64(&lanes)
+        + x[i..].iter().map(|v| v.abs() as f64).sum::<f64>()
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn asum_f32_sse2(x: &[f32]) -> f64 {
+    let px = x.as_ptr();
+    let sign = _mm_set1_pd(-0.0);
+    let mut acc = _mm_setzero_pd();
+    let mut i = 0;
+    while i + 4 <= x.len() {
+        let v = _mm_loadu_ps(px.add(i));
+        let low = _mm_cvtps_pd(v);
+        let high = _mm_cvtps_pd(_mm_movehl_ps(v, v));
+        acc = _mm_add_pd(acc, _mm_andnot_pd(sign, low));
+        acc = _mm_add_pd(acc, _mm_andnot_pd(sign, high));
+        i += 4;
+    }
+    let mut lanes = [0.0; 2];
+    _mm_storeu_pd(lanes.as_mut_ptr(), acc);
+    hsum_f64(&lanes)
+        + x[i..].iter().map(|v| v.abs() as f64).sum::<f64>()
+}
+
+// max(v, acc) returns acc when v is NaN, so NaNs never win
+#[target_feature(enable = "avx2,fma")]
+unsafe fn amax_f64_avx2(x: &[f64]) -> f64 {
+    let px = x.as_ptr();
+    let sign = _mm256_set1_pd(-0.0);
+    let mut acc = _mm256_setzero_pd();
+    let mut i = 0;
+    while i + 4 <= x.len() {
+        let v = _mm256_andnot_pd(
+            sign,
+            _mm256_loadu_pd(px.add(i)),
+        );
+        acc = _mm256_max_pd(v, acc);
+        i += 4;
+    }
+    let mut lanes = [0.0; 4];
+    _mm256_storeu_pd(lanes.as_mut_ptr(), acc);
+    max_of(lanes.iter().chain(&x[i..]).map(|v| v.abs()))
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn amax_f64_sse2(x: &[f64]) -> f64 {
+    let px = x.as_ptr();
+    let sign = _mm_set1_pd(-0.0);
+    let mut acc = _mm_setzero_pd();
+    let mut i = 0;
+    while i + 2 <= x.len() {
+        let v =
+            _mm_andnot_pd(sign, _mm_loadu_pd(px.add(i)));
+        acc = _mm_max_pd(v, acc);
+        i += 2;
+    }
+    let mut lanes = [0.0; 2];
+    _mm_storeu_pd(lanes.as_mut_ptr(), acc);
+    max_of(lanes.iter().chain(&x[i..]).map(|v| v.abs()))
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn amax_f32_sse2(x: &[f32]) -> f32 {
+    let px = x.as_ptr();
+    let sign = _mm_set1_ps(-0.0);
+    let mut acc = _mm_setzero_ps();
+    let mut i = 0;
+    while i + 4 <= x.len() {
+        let v =
+            _mm_andnot_ps(sign, _mm_loadu_ps(px.add(i)));
+        acc = _mm_max_ps(v, acc);
+        i += 4;
+    }
+    let mut lanes = [0.0; 4];
+    _mm_storeu_ps(lanes.as_mut_ptr(), acc);
+    let mut to_return = 0.0;
+    for v in lanes.iter().chain(&x[i..]).map(|v| v.abs()) {
+        if v > to_return {
+            to_return = v;
+        }
+    }
+    to_return
+}
+
+fn max_of(values: impl Iterator<Item = f64>) -> f64 {
+    let mut to_return = 0.0;
+    for v in values {
+        if v > to_return {
+            to_return = v;
+        }
+    }
+    to_return
+}
+
+#[target_feature(enable = "avx")]
+unsafe fn tile_f64_avx(
+    a: &[f64],
+    b: &[f64],
+    acc: &mut [[f64; 4]; 4],
+) {
+    let kb = usize::min(a.len(), b.len()) / 4;
+    let (pa, pb) = (a.as_ptr(), b.as_ptr());
+    let mut c = [_mm256_setzero_pd(); 4];
+    for (cj, column) in c.iter_mut().zip(acc.iter()) {
+        *cj = _mm256_loadu_pd(column.as_ptr());
+    }
+    for p in 0..kb {
+        let va = _mm256_loadu_pd(pa.add(4 * p));
+        for (j, cj) in c.iter_mut().enumerate() {
+            let vb = _mm256_set1_pd(*pb.add(4 * p + j));
+            *cj = _mm256_add_pd(*cj, _mm256_mul_pd(va, vb));
+        }
+    }
+    for (cj, column) in c.iter().zip(acc.iter_mut()) {
+        _mm256_storeu_pd(column.as_mut_ptr(), *cj);
+    }
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn tile_f64_sse2(
+    a: &[f64],
+    b: &[f64],
+    acc: &mut [[f64; 4]; 4],
+) {
+    let kb = usize::min(a.len(), b.len()) / 4;
+    let (pa, pb) = (a.as_ptr(), b.as_ptr());
+    let mut c = [[_mm_setzero_pd(); 2]; 4];
+    for (cj, column) in c.iter_mut().zip(acc.iter()) {
+        cj[0] = _mm_loadu_pd(column.as_ptr());
+        cj[1] = _mm_loadu_pd(column.as_ptr().add(2));
+    }
+    for p in 0..kb {
+        let va0 = _mm_loadu_pd(pa.add(4 * p));
+        let va1 = _mm_loadu_pd(pa.add(4 * p + 2));
+        for (j, cj) in c.iter_mut().enumerate() {
+            let vb = _mm_set1_pd(*pb.add(4 * p + j));
+            cj[0] = _mm_add_pd(cj[0], _mm_mul_pd(va0, vb));
+            cj[1] = _mm_add_pd(cj[1], _mm_mul_pd(va1, vb));
+        }
+    }
+    for (cj, column) in c.iter().zip(acc.iter_mut()) {
+        _mm_storeu_pd(column.as_mut_ptr(), cj[0]);
+        _mm_storeu_pd(column.as_mut_ptr().add(2), cj[1]);
+    }
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn tile_f32_sse2(
+    a: &[f32],
+    b: &[f32],
+    acc: &mut [[f32; 4]; 4],
+) {
+    let kb = usize::min(a.len(), b.len()) / 4;
+    let (pa, pb) = (a.as_ptr(), b.as_ptr());
+    let mut c = [_mm_setzero_ps(); 4];
+    for (cj, column) in c.iter_mut().zip(acc.iter()) {
+        *cj = _mm_loadu_ps(column.as_ptr());
+    }
+    for p in 0..kb {
+        let va = _mm_loadu_ps(pa.add(4 * p));
+        for (j, cj) in c.iter_mut().enumerate() {
+            let vb = _mm_set1_ps(*pb.add(4 * p + j));
+            *cj = _mm_add_ps(*cj, _mm_mul_ps(va, vb));
+        }
+    }
+    for (cj, column) in c.iter().zip(acc.iter_mut()) {
+        _mm_storeu_ps(column.as_mut_ptr(), *cj);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    errors::LinAlgError,
+    solvers::{
+        check_system, copy, initial_guess,
+        ConvergenceReport, LinearOperator, Preconditioner,
+        SolverOptions,
+    },
+    traits::Field,
+    vector::Vector,
+};
+
+// Stabilized biconjugate gradient for a general square A, preconditioned
+// on the right. Stops early on a breakdown (ρ or ω reaching zero).
+pub fn bicgstab<K, A, P>(
+    a: &A,
+    b: &Vector<K>,
+    x0: Option<&Vector<K>>,
+    preconditioner: &P,
+    options: &SolverOptions,
+) -> Result<(Vector<K>, ConvergenceReport), LinAlgError>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+    P: Preconditioner<K> + ?Sized,
+{
+    check_system(a, b)?;
+    let b_norm = b.norm();
+    let mut x = initial_guess(x0, b.size())?;
+    if b_norm == 0.0 {
+        // x = 0 solves it exactly
+        x.scale_mut(K::default())?;
+        return Ok((
+            x,
+            ConvergenceReport::new(vec![0.0], options),
+        ));
+    }
+
+    let mut r = b.sub(&a.apply(&x)?)?;
+    let r_hat = copy(&r)?;
+    let mut p = Vector::zero(b.size())?;
+    let mut v = Vector::zero(b.size())?;
+    let (mut rho, mut alpha, mut omega) =
+        (K::one(), K::one(), K::one());
+    let mut history = vec![r.norm() / b_norm];
+    while history[history.len() - 1] > options.tol
+        && history.len() <= options.max_iterations
+    {
+        let rho_next = r_hat.dot(&r)?;
+        if rho_next == K::default() {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        // p ← r + β·(p − ω·v)
+        p.axpy(K::default() - omega, &v)?;
+        p.scale_mut(beta)?;
+        p.add_assign(&r)?;
+
+        let y = preconditioner.apply(&p)?;
+        v = a.apply(&y)?;
+        let r_hat_v = r_hat.dot(&v)?;
+        if r_hat_v == K::default() {
+            break;
+        }
+        alpha = rho / r_hat_v;
+        x.axpy(alpha, &y)?;
+        // s ← r − α·v, kept in r
+        r.axpy(K::default() - alpha, &v)?;
+        let s_norm = r.norm() / b_norm;
+        if s_norm <= options.tol {
+            history.push(s_norm);
+            break;
+        }
+
+        let z = preconditioner.apply(&r)?;
+        let t = a.apply(&z)?;
+        let tt = t.dot(&t)?;
+        if tt == K::default() {
+            history.push(s_norm);
+            break;
+        }
+        omega = t.dot(&r)? / tt;
+        x.axpy(omega, &z)?;
+        r.axpy(K::default() - omega, &t)?;
+        history.push(r.norm() / b_norm);
+        if omega == K::default() {
+            break;
+        }
+    }
+    Ok((x, ConvergenceReport::new(history, options)))
+}
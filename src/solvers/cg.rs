@@ -0,0 +1,65 @@
+use crate::{
+    errors::LinAlgError,
+    solvers::{
+        check_system, copy, initial_guess,
+        ConvergenceReport, LinearOperator, Preconditioner,
+        SolverOptions,
+    },
+    traits::Field,
+    vector::Vector,
+};
+
+// Preconditioned conjugate gradient for Hermitian positive definite A,
+// starting from x0 (or zero)
+pub fn cg<K, A, P>(
+    a: &A,
+    b: &Vector<K>,
+    x0: Option<&Vector<K>>,
+    preconditioner: &P,
+    options: &SolverOptions,
+) -> Result<(Vector<K>, ConvergenceReport), LinAlgError>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+    P: Preconditioner<K> + ?Sized,
+{
+    check_system(a, b)?;
+    let b_norm = b.norm();
+    let mut x = initial_guess(x0, b.size())?;
+    if b_norm == 0.0 {
+        // x = 0 solves it exactly
+        x.scale_mut(K::default())?;
+        return Ok((
+            x,
+            ConvergenceReport::new(vec![0.0], options),
+        ));
+    }
+
+    let mut r = b.sub(&a.apply(&x)?)?;
+    let mut z = preconditioner.apply(&r)?;
+    let mut p = copy(&z)?;
+    let mut rz = r.dot(&z)?;
+    let mut history = vec![r.norm() / b_norm];
+    while history[history.len() - 1] > options.tol
+        && history.len() <= options.max_iterations
+    {
+        let ap = a.apply(&p)?;
+        let pap = p.dot(&ap)?;
+        if pap == K::default() {
+            break;
+        }
+        let alpha = rz / pap;
+        x.axpy(alpha, &p)?;
+        r.axpy(K::default() - alpha, &ap)?;
+        history.push(r.norm() / b_norm);
+
+        z = preconditioner.apply(&r)?;
+        let rz_next = r.dot(&z)?;
+        let beta = rz_next / rz;
+        rz = rz_next;
+        // p ← z + β·p
+        p.scale_mut(beta)?;
+        p.add_assign(&z)?;
+    }
+    Ok((x, ConvergenceReport::new(history, options)))
+}
@@ -0,0 +1,134 @@
+use crate::{
+    errors::LinAlgError,
+    solvers::{
+        check_system, initial_guess, ConvergenceReport,
+        LinearOperator, Preconditioner, SolverOptions,
+    },
+    traits::Field,
+    vector::Vector,
+};
+
+// Restarted GMRES(m) for a general square A, preconditioned on the right
+// so the residual it minimizes is the true one. Every `restart` steps the
+// Krylov basis is dropped and rebuilt from the current residual.
+pub fn gmres<K, A, P>(
+    a: &A,
+    b: &Vector<K>,
+    x0: Option<&Vector<K>>,
+    preconditioner: &P,
+    restart: usize,
+    options: &SolverOptions,
+) -> Result<(Vector<K>, ConvergenceReport), LinAlgError>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+    P: Preconditioner<K> + ?Sized,
+{
+    check_system(a, b)?;
+    if restart == 0 {
+        return Err(LinAlgError::OperationNonConforming {
+            operation: "GMRES restart".to_string(),
+        });
+    }
+    let b_norm = b.norm();
+    let mut x = initial_guess(x0, b.size())?;
+    if b_norm == 0.0 {
+        // x = 0 solves it exactly
+        x.scale_mut(K::default())?;
+        return Ok((
+            x,
+            ConvergenceReport::new(vec![0.0], options),
+        ));
+    }
+
+    let mut r = b.sub(&a.apply(&x)?)?;
+    let mut history = vec![r.norm() / b_norm];
+    while history[history.len() - 1] > options.tol
+        && history.len() <= options.max_iterations
+    {
+        let beta = r.norm();
+        r.scale_mut(K::ff64(1.0 / beta))?;
+        // orthonormal basis V, the preconditioned Z = M⁻¹·V and the
+        // columns of the Hessenberg matrix, reduced to R by rotations
+        let mut v = vec![r];
+        let mut z: Vec<Vector<K>> =
+            Vec::with_capacity(restart);
+        let mut h: Vec<Vec<K>> =
+            Vec::with_capacity(restart);
+        let mut rotations: Vec<(K, K)> =
+            Vec::with_capacity(restart);
+        // right hand side of the small least squares problem
+        let mut g = vec![K::ff64(beta)];
+
+        for j in 0..restart {
+            z.push(preconditioner.apply(&v[j])?);
+            let mut w = a.apply(&z[j])?;
+            let mut column = Vec::with_capacity(j + 2);
+            for vi in &v {
+                let hij = vi.dot(&w)?;
+                w.axpy(K::default() - hij, vi)?;
+                column.push(hij);
+            }
+            let next_norm = w.norm();
+            column.push(K::ff64(next_norm));
+
+            for (i, &(c, s)) in rotations.iter().enumerate()
+            {
+                let (x1, x2) = (column[i], column[i + 1]);
+                column[i] = c.conj() * x1 + s.conj() * x2;
+                column[i + 1] = c * x2 - s * x1;
+            }
+            let (c, s) = rotation(column[j], column[j + 1]);
+            column[j] = c.conj() * column[j]
+                + s.conj() * column[j + 1];
+            column[j + 1] = K::default();
+            g.push(K::default() - s * g[j]);
+            g[j] = c.conj() * g[j];
+            rotations.push((c, s));
+            h.push(column);
+
+            history.push(g[j + 1].norm() / b_norm);
+            if history[history.len() - 1] <= options.tol
+                || history.len() > options.max_iterations
+                || next_norm == 0.0
+            {
+                break;
+            }
+            w.scale_mut(K::ff64(1.0 / next_norm))?;
+            v.push(w);
+        }
+
+        // back substitution with R, then x ← x + Z·y
+        let k = h.len();
+        let mut y = vec![K::default(); k];
+        for i in (0..k).rev() {
+            let mut acc = g[i];
+            for l in i + 1..k {
+                acc = acc - h[l][i] * y[l];
+            }
+            y[i] = acc / h[i][i];
+        }
+        for (yi, zi) in y.iter().zip(&z) {
+            x.axpy(*yi, zi)?;
+        }
+        // the rotated estimate drifts, so restart from the true residual
+        r = b.sub(&a.apply(&x)?)?;
+        let last = history.len() - 1;
+        history[last] = r.norm() / b_norm;
+    }
+    Ok((x, ConvergenceReport::new(history, options)))
+}
+
+// Unitary rotation (c, s) with [c̄ s̄; −s c]·[a; b] = [r; 0]
+fn rotation<K>(a: K, b: K) -> (K, K)
+where
+    K: Field,
+{
+    let r =
+        (a.norm() * a.norm() + b.norm() * b.norm()).sqrt();
+    if r == 0.0 {
+        return (K::one(), K::default());
+    }
+    let inverse = K::ff64(1.0 / r);
+    (a * inverse, b * inverse)
+}
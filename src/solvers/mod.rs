@@ -0,0 +1,192 @@
+// Iterative solvers that only need the product A·x
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
+mod preconditioner;
+
+pub use preconditioner::{
+    Identity, IncompleteCholesky, Jacobi, Preconditioner,
+};
+
+use crate::{
+    errors::LinAlgError,
+    matrix::Matrix,
+    sparse::{CscMatrix, CsrMatrix},
+    traits::Field,
+    vector::Vector,
+};
+
+// Anything that can be multiplied with a vector
+pub trait LinearOperator<K>
+where
+    K: Field,
+{
+    fn size(&self) -> (usize, usize);
+    // A·x
+    fn apply(
+        &self,
+        x: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError>;
+    // Main diagonal, used by the Jacobi preconditioner
+    fn diagonal(&self) -> Result<Vector<K>, LinAlgError>;
+}
+
+impl<K> LinearOperator<K> for Matrix<K>
+where
+    K: Field,
+{
+    fn size(&self) -> (usize, usize) {
+        Matrix::size(self)
+    }
+    fn apply(
+        &self,
+        x: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        self.mul_vec(x)
+    }
+    fn diagonal(&self) -> Result<Vector<K>, LinAlgError> {
+        Matrix::diagonal(self).to_vector()
+    }
+}
+
+impl<K> LinearOperator<K> for CsrMatrix<K>
+where
+    K: Field,
+{
+    fn size(&self) -> (usize, usize) {
+        CsrMatrix::size(self)
+    }
+    fn apply(
+        &self,
+        x: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        self.mul_vec(x)
+    }
+    fn diagonal(&self) -> Result<Vector<K>, LinAlgError> {
+        let (n, m) = CsrMatrix::size(self);
+        let mut to_return =
+            Vec::with_capacity(usize::min(n, m));
+        for i in 1..=usize::min(n, m) {
+            to_return.push(self.el(i, i)?);
+        }
+        Vector::new(to_return)
+    }
+}
+
+impl<K> LinearOperator<K> for CscMatrix<K>
+where
+    K: Field,
+{
+    fn size(&self) -> (usize, usize) {
+        CscMatrix::size(self)
+    }
+    fn apply(
+        &self,
+        x: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        self.mul_vec(x)
+    }
+    fn diagonal(&self) -> Result<Vector<K>, LinAlgError> {
+        let (n, m) = CscMatrix::size(self);
+        let mut to_return =
+            Vec::with_capacity(usize::min(n, m));
+        for i in 1..=usize::min(n, m) {
+            to_return.push(self.el(i, i)?);
+        }
+        Vector::new(to_return)
+    }
+}
+
+// Stopping rule: ‖b − A·x‖ ≤ tol·‖b‖ or `max_iterations` steps
+#[derive(Clone, Copy)]
+pub struct SolverOptions {
+    pub tol: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for SolverOptions {
+    fn default() -> SolverOptions {
+        SolverOptions {
+            tol: 1e-10,
+            max_iterations: 1000,
+        }
+    }
+}
+
+// How an iterative solve ended
+#[derive(Clone, Debug)]
+pub struct ConvergenceReport {
+    pub converged: bool,
+    pub iterations: usize,
+    // ‖b − A·x‖ / ‖b‖ for the returned x
+    pub relative_residual: f64,
+    // Relative residual before the first step and after every step
+    pub residual_history: Vec<f64>,
+}
+
+impl ConvergenceReport {
+    pub(crate) fn new(
+        residual_history: Vec<f64>,
+        options: &SolverOptions,
+    ) -> ConvergenceReport {
+        let relative_residual =
+            residual_history.last().copied().unwrap_or(0.0);
+        ConvergenceReport {
+            converged: relative_residual <= options.tol,
+            iterations: residual_history
+                .len()
+                .saturating_sub(1),
+            relative_residual,
+            residual_history,
+        }
+    }
+}
+
+// Check that A is square and matches the right hand side
+pub(crate) fn check_system<K, A>(
+    a: &A,
+    b: &Vector<K>,
+) -> Result<(), LinAlgError>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+{
+    let (n, m) = a.size();
+    if n != m || n != b.size() {
+        return Err(LinAlgError::OperationNonConforming {
+            operation:
+                "Not a square system. Can't solve it."
+                    .to_string(),
+        });
+    }
+    Ok(())
+}
+
+// Copy of the initial guess, zero when there is none
+pub(crate) fn initial_guess<K>(
+    x0: Option<&Vector<K>>,
+    n: usize,
+) -> Result<Vector<K>, LinAlgError>
+where
+    K: Field,
+{
+    match x0 {
+        Some(x0) if x0.size() != n => {
+            Err(LinAlgError::OperationNonConforming {
+                operation: "Initial guess".to_string(),
+            })
+        }
+        Some(x0) => copy(x0),
+        None => Vector::zero(n),
+    }
+}
+
+// Copy of a vector, since `Vector` isn't `Clone`
+pub(crate) fn copy<K>(
+    x: &Vector<K>,
+) -> Result<Vector<K>, LinAlgError>
+where
+    K: Field,
+{
+    Vector::new(x.matrix.elements.clone())
+}
@@ -0,0 +1,223 @@
+use crate::{
+    errors::LinAlgError, solvers::LinearOperator,
+    sparse::CsrMatrix, traits::Field, vector::Vector,
+};
+
+// An approximation M of A that is cheap to invert: apply returns M⁻¹·r
+pub trait Preconditioner<K>
+where
+    K: Field,
+{
+    fn apply(
+        &self,
+        r: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError>;
+}
+
+// No preconditioning, M = I
+pub struct Identity;
+
+impl<K> Preconditioner<K> for Identity
+where
+    K: Field,
+{
+    fn apply(
+        &self,
+        r: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        Vector::new(r.matrix.elements.clone())
+    }
+}
+
+// Diagonal scaling, M = diag(A)
+pub struct Jacobi<K>
+where
+    K: Field,
+{
+    inverse_diagonal: Vec<K>,
+}
+
+impl<K> Jacobi<K>
+where
+    K: Field,
+{
+    // Fails when the diagonal has a zero
+    pub fn new<A>(a: &A) -> Result<Jacobi<K>, LinAlgError>
+    where
+        A: LinearOperator<K> + ?Sized,
+    {
+        let diagonal = a.diagonal()?;
+        let mut inverse_diagonal =
+            Vec::with_capacity(diagonal.size());
+        for d in &diagonal.matrix.elements {
+            if *d == K::default() {
+                return Err(LinAlgError::SinglarMatrix);
+            }
+            inverse_diagonal.push(K::one() / *d);
+        }
+        Ok(Jacobi { inverse_diagonal })
+    }
+}
+
+impl<K> Preconditioner<K> for Jacobi<K>
+where
+    K: Field,
+{
+    fn apply(
+        &self,
+        r: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        if r.size() != self.inverse_diagonal.len() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "Preconditioner".to_string(),
+                },
+            );
+        }
+        Vector::new(
+            r.matrix
+                .elements
+                .iter()
+                .zip(&self.inverse_diagonal)
+                .map(|(x, d)| *d * *x)
+                .collect(),
+        )
+    }
+}
+
+// Zero fill-in incomplete Cholesky, M = L·Lᴴ where L keeps the sparsity
+// pattern of the lower triangle of A
+pub struct IncompleteCholesky<K>
+where
+    K: Field,
+{
+    // rows of L with sorted columns, the diagonal entry last
+    l: CsrMatrix<K>,
+}
+
+impl<K> IncompleteCholesky<K>
+where
+    K: Field,
+{
+    // Factorize a Hermitian positive definite matrix; only its lower
+    // triangle is read
+    pub fn new(
+        a: &CsrMatrix<K>,
+    ) -> Result<IncompleteCholesky<K>, LinAlgError> {
+        let (n, m) = a.size();
+        if n != m {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Not a square matrix. Can't factorize it."
+                    .to_string(),
+            });
+        }
+        let mut triplets = Vec::with_capacity(a.nnz());
+        for i in 1..=n {
+            let mut has_diagonal = false;
+            for k in a.row_ptr()[i - 1]..a.row_ptr()[i] {
+                let j = a.col_indices()[k];
+                if j <= i {
+                    triplets.push((i, j, a.values()[k]));
+                    has_diagonal |= j == i;
+                }
+            }
+            if !has_diagonal {
+                return Err(
+                    LinAlgError::NotPositiveDefinite,
+                );
+            }
+        }
+        let mut l =
+            CsrMatrix::from_triplets(n, n, &triplets)?;
+        let row_ptr = l.row_ptr().to_vec();
+        let col_idx = l.col_indices().to_vec();
+        let values = l.values_mut();
+
+        for i in 1..=n {
+            let row_i = row_ptr[i - 1]..row_ptr[i];
+            for k in row_i.clone() {
+                let j = col_idx[k];
+                // Σ l_ip·conj(l_jp) over the columns p < j both rows store
+                let mut sum = K::default();
+                let row_j = row_ptr[j - 1]..row_ptr[j];
+                let (mut a, mut b) =
+                    (row_i.start, row_j.start);
+                while a < k && b < row_j.end - 1 {
+                    match col_idx[a].cmp(&col_idx[b]) {
+                        std::cmp::Ordering::Less => a += 1,
+                        std::cmp::Ordering::Greater => {
+                            b += 1
+                        }
+                        std::cmp::Ordering::Equal => {
+                            sum = sum
+                                + values[a]
+                                    * values[b].conj();
+                            a += 1;
+                            b += 1;
+                        }
+                    }
+                }
+                if j < i {
+                    // the diagonal of row j is its last entry
+                    values[k] = (values[k] - sum)
+                        / values[row_j.end - 1];
+                } else {
+                    let d = (values[k] - sum).tf64();
+                    if d.is_nan() || d <= 0.0 {
+                        return Err(LinAlgError::NotPositiveDefinite);
+                    }
+                    values[k] = K::ff64(d.sqrt());
+                }
+            }
+        }
+        Ok(IncompleteCholesky { l })
+    }
+
+    // The incomplete factor L
+    pub fn l(&self) -> &CsrMatrix<K> {
+        &self.l
+    }
+}
+
+impl<K> Preconditioner<K> for IncompleteCholesky<K>
+where
+    K: Field,
+{
+    // Solve L·y = r, then Lᴴ·z = y
+    fn apply(
+        &self,
+        r: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        let (n, _) = self.l.size();
+        if r.size() != n {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "Preconditioner".to_string(),
+                },
+            );
+        }
+        let row_ptr = self.l.row_ptr();
+        let col_idx = self.l.col_indices();
+        let values = self.l.values();
+        let mut z = r.matrix.elements.clone();
+        for i in 0..n {
+            let (start, end) =
+                (row_ptr[i], row_ptr[i + 1] - 1);
+            let mut acc = z[i];
+            for k in start..end {
+                acc = acc - values[k] * z[col_idx[k] - 1];
+            }
+            z[i] = acc / values[end];
+        }
+        for i in (0..n).rev() {
+            let (start, end) =
+                (row_ptr[i], row_ptr[i + 1] - 1);
+            z[i] = z[i] / values[end].conj();
+            for k in start..end {
+                let j = col_idx[k] - 1;
+                z[j] = z[j] - values[k].conj() * z[i];
+            }
+        }
+        Vector::new(z)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    errors::LinAlgError,
+    matrix::Matrix,
+    sparse::{CscMatrix, CsrMatrix},
+    traits::Field,
+};
+
+// Collect (row, column, value) triplets one at a time, e.g. while assembling
+// element matrices. Repeated positions are summed when the matrix is built.
+#[derive(Clone)]
+pub struct CooBuilder<K>
+where
+    K: Field,
+{
+    size: (usize, usize),
+    triplets: Vec<(usize, usize, K)>,
+}
+
+impl<K> CooBuilder<K>
+where
+    K: Field,
+{
+    // Start an empty n x m matrix
+    pub fn new(n: usize, m: usize) -> CooBuilder<K> {
+        CooBuilder::with_capacity(n, m, 0)
+    }
+
+    // Start an empty n x m matrix with room for `capacity` entries
+    pub fn with_capacity(
+        n: usize,
+        m: usize,
+        capacity: usize,
+    ) -> CooBuilder<K> {
+        CooBuilder {
+            size: (n, m),
+            triplets: Vec::with_capacity(capacity),
+        }
+    }
+
+    // Retrieve the size of the matrix being built
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    // Number of pushed entries, counting repeated positions
+    pub fn len(&self) -> usize {
+        self.triplets.len()
+    }
+
+    // Whether nothing was pushed yet
+    pub fn is_empty(&self) -> bool {
+        self.triplets.is_empty()
+    }
+
+    // Add v at the 1-based position (i, j), on top of anything already there
+    pub fn push(
+        &mut self,
+        i: usize,
+        j: usize,
+        v: K,
+    ) -> Result<(), LinAlgError> {
+        let (n, m) = self.size;
+        if i == 0 || j == 0 || i > n || j > m {
+            return Err(LinAlgError::OutofBoundsMatrix {
+                size: self.size,
+                recieved: (i, j),
+            });
+        }
+        self.triplets.push((i, j, v));
+        Ok(())
+    }
+
+    // Build the compressed sparse row matrix
+    pub fn to_csr(
+        &self,
+    ) -> Result<CsrMatrix<K>, LinAlgError> {
+        let (n, m) = self.size;
+        CsrMatrix::from_triplets(n, m, &self.triplets)
+    }
+
+    // Build the compressed sparse column matrix
+    pub fn to_csc(
+        &self,
+    ) -> Result<CscMatrix<K>, LinAlgError> {
+        let (n, m) = self.size;
+        CscMatrix::from_triplets(n, m, &self.triplets)
+    }
+
+    // Build the dense matrix
+    pub fn to_matrix(
+        &self,
+    ) -> Result<Matrix<K>, LinAlgError> {
+        let (n, m) = self.size;
+        let mut to_return = Matrix::<K>::zero(n, m)?;
+        for &(i, j, v) in &self.triplets {
+            to_return.set(i, j, to_return.el(i, j)? + v)?;
+        }
+        Ok(to_return)
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, sparse::CsrMatrix,
+    traits::Field, vector::Vector,
+};
+
+// Compressed sparse column storage. The arrays of A in CSC are exactly
+// those of Aᵀ in CSR, so the transpose is stored and reused.
+#[derive(PartialEq, Clone)]
+pub struct CscMatrix<K>
+where
+    K: Field,
+{
+    transpose: CsrMatrix<K>,
+}
+
+impl<K> CscMatrix<K>
+where
+    K: Field,
+{
+    pub(crate) fn from_transpose(
+        transpose: CsrMatrix<K>,
+    ) -> CscMatrix<K> {
+        CscMatrix { transpose }
+    }
+
+    // Build an n x m matrix from (row, column, value) triplets,
+    // adding up repeated positions
+    pub fn from_triplets(
+        n: usize,
+        m: usize,
+        triplets: &[(usize, usize, K)],
+    ) -> Result<CscMatrix<K>, LinAlgError> {
+        for &(i, j, _) in triplets {
+            if i == 0 || j == 0 || i > n || j > m {
+                return Err(
+                    LinAlgError::OutofBoundsMatrix {
+                        size: (n, m),
+                        recieved: (i, j),
+                    },
+                );
+            }
+        }
+        let swapped: Vec<(usize, usize, K)> = triplets
+            .iter()
+            .map(|&(i, j, x)| (j, i, x))
+            .collect();
+        Ok(CscMatrix {
+            transpose: CsrMatrix::from_triplets(
+                m, n, &swapped,
+            )?,
+        })
+    }
+
+    // Store the nonzero entries of a dense matrix
+    pub fn from_matrix(
+        a: &Matrix<K>,
+    ) -> Result<CscMatrix<K>, LinAlgError> {
+        Ok(CscMatrix {
+            transpose: CsrMatrix::from_matrix(&a.tr()?)?,
+        })
+    }
+
+    // Expand into a dense matrix
+    pub fn to_matrix(
+        &self,
+    ) -> Result<Matrix<K>, LinAlgError> {
+        self.transpose.to_matrix()?.tr()
+    }
+
+    // Same matrix in compressed sparse row storage
+    pub fn to_csr(
+        &self,
+    ) -> Result<CsrMatrix<K>, LinAlgError> {
+        self.transpose.tr()
+    }
+
+    // Retrieve the size of the matrix
+    pub fn size(&self) -> (usize, usize) {
+        let (m, n) = self.transpose.size();
+        (n, m)
+    }
+
+    // Number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.transpose.nnz()
+    }
+
+    // Offsets of each column into `row_indices` and `values`
+    pub fn col_ptr(&self) -> &[usize] {
+        self.transpose.row_ptr()
+    }
+
+    // Row of each stored entry
+    pub fn row_indices(&self) -> &[usize] {
+        self.transpose.col_indices()
+    }
+
+    // Value of each stored entry
+    pub fn values(&self) -> &[K] {
+        self.transpose.values()
+    }
+
+    // Access a specific element, zero when it isn't stored
+    pub fn el(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Result<K, LinAlgError> {
+        let (n, m) = self.size();
+        if i == 0 || j == 0 || i > n || j > m {
+            return Err(LinAlgError::OutofBoundsMatrix {
+                size: (n, m),
+                recieved: (i, j),
+            });
+        }
+        self.transpose.el(j, i)
+    }
+
+    // Multiply the matrix with a dense vector, scattering one column at a time
+    pub fn mul_vec(
+        &self,
+        x: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        let (n, m) = self.size();
+        if x.size() != m {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Sizes not conform to multiplication"
+                    .to_string(),
+            });
+        }
+        let x = &x.matrix.elements;
+        let rows = self.row_indices();
+        let values = self.values();
+        let mut to_return = vec![K::default(); n];
+        for j in 1..=m {
+            for k in self.transpose.row_range(j) {
+                to_return[rows[k] - 1] = to_return
+                    [rows[k] - 1]
+                    + values[k] * x[j - 1];
+            }
+        }
+        Vector::new(to_return)
+    }
+
+    // Transpose, still in compressed sparse column storage
+    pub fn tr(&self) -> Result<CscMatrix<K>, LinAlgError> {
+        Ok(CscMatrix {
+            transpose: self.transpose.tr()?,
+        })
+    }
+
+    // Add a sparse matrix of the same dimensions
+    pub fn add(
+        &self,
+        other: &CscMatrix<K>,
+    ) -> Result<CscMatrix<K>, LinAlgError> {
+        Ok(CscMatrix {
+            transpose: self
+                .transpose
+                .add(&other.transpose)?,
+        })
+    }
+
+    // Scale the matrix by a factor
+    pub fn scl(
+        &self,
+        scaling: K,
+    ) -> Result<CscMatrix<K>, LinAlgError> {
+        Ok(CscMatrix {
+            transpose: self.transpose.scl(scaling)?,
+        })
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{
+    errors::LinAlgError, matrix::Matrix, sparse::CscMatrix,
+    traits::Field, vector::Vector,
+};
+
+// Compressed sparse row storage: the entries of row i are
+// values[row_ptr[i - 1]..row_ptr[i]], with their columns in col_idx
+// sorted in increasing order. Indices are 1-based like the rest of the crate.
+#[derive(PartialEq, Clone)]
+pub struct CsrMatrix<K>
+where
+    K: Field,
+{
+    size: (usize, usize),
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<K>,
+}
+
+impl<K> CsrMatrix<K>
+where
+    K: Field,
+{
+    // Build an n x m matrix from (row, column, value) triplets,
+    // adding up repeated positions
+    pub fn from_triplets(
+        n: usize,
+        m: usize,
+        triplets: &[(usize, usize, K)],
+    ) -> Result<CsrMatrix<K>, LinAlgError> {
+        for &(i, j, _) in triplets {
+            if i == 0 || j == 0 || i > n || j > m {
+                return Err(
+                    LinAlgError::OutofBoundsMatrix {
+                        size: (n, m),
+                        recieved: (i, j),
+                    },
+                );
+            }
+        }
+        let mut order: Vec<usize> =
+            (0..triplets.len()).collect();
+        order.sort_by_key(|&k| {
+            (triplets[k].0, triplets[k].1)
+        });
+
+        let mut row_ptr = vec![0; n + 1];
+        let mut col_idx: Vec<usize> =
+            Vec::with_capacity(order.len());
+        let mut values: Vec<K> =
+            Vec::with_capacity(order.len());
+        let mut last = (0, 0);
+        for k in order {
+            let (i, j, x) = triplets[k];
+            if (i, j) == last {
+                let end = values.len() - 1;
+                values[end] = values[end] + x;
+                continue;
+            }
+            row_ptr[i] += 1;
+            col_idx.push(j);
+            values.push(x);
+            last = (i, j);
+        }
+        // turn the per-row counts into offsets
+        for i in 1..=n {
+            row_ptr[i] += row_ptr[i - 1];
+        }
+        Ok(CsrMatrix {
+            size: (n, m),
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    // Store the nonzero entries of a dense matrix
+    pub fn from_matrix(
+        a: &Matrix<K>,
+    ) -> Result<CsrMatrix<K>, LinAlgError> {
+        let (n, m) = a.size();
+        let mut row_ptr = Vec::with_capacity(n + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+        for i in 0..n {
+            for j in 0..m {
+                let x = a.elements[i + j * n];
+                if x != K::default() {
+                    col_idx.push(j + 1);
+                    values.push(x);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        Ok(CsrMatrix {
+            size: (n, m),
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    // Expand into a dense matrix
+    pub fn to_matrix(
+        &self,
+    ) -> Result<Matrix<K>, LinAlgError> {
+        let (n, m) = self.size;
+        let mut to_return = Matrix::<K>::zero(n, m)?;
+        for i in 1..=n {
+            for k in self.row_range(i) {
+                let j = self.col_idx[k];
+                to_return.elements[(i - 1) + (j - 1) * n] =
+                    self.values[k];
+            }
+        }
+        Ok(to_return)
+    }
+
+    // Same matrix in compressed sparse column storage
+    pub fn to_csc(
+        &self,
+    ) -> Result<CscMatrix<K>, LinAlgError> {
+        Ok(CscMatrix::from_transpose(self.tr()?))
+    }
+
+    // Retrieve the size of the matrix
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    // Number of stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    // Offsets of each row into `col_indices` and `values`
+    pub fn row_ptr(&self) -> &[usize] {
+        &self.row_ptr
+    }
+
+    // Column of each stored entry
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_idx
+    }
+
+    // Value of each stored entry
+    pub fn values(&self) -> &[K] {
+        &self.values
+    }
+
+    // Stored values, editable in place since the pattern stays fixed
+    pub(crate) fn values_mut(&mut self) -> &mut [K] {
+        &mut self.values
+    }
+
+    // Positions in `values` of the entries of the 1-based row i
+    pub(crate) fn row_range(
+        &self,
+        i: usize,
+    ) -> std::ops::Range<usize> {
+        self.row_ptr[i - 1]..self.row_ptr[i]
+    }
+
+    // Access a specific element, zero when it isn't stored
+    pub fn el(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Result<K, LinAlgError> {
+        let (n, m) = self.size;
+        if i == 0 || j == 0 || i > n || j > m {
+            return Err(LinAlgError::OutofBoundsMatrix {
+                size: self.size,
+                recieved: (i, j),
+            });
+        }
+        let range = self.row_range(i);
+        let start = range.start;
+        Ok(match self.col_idx[range].binary_search(&j) {
+            Ok(k) => self.values[start + k],
+            Err(_) => K::default(),
+        })
+    }
+
+    // Multiply the matrix with a dense vector
+    pub fn mul_vec(
+        &self,
+        x: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        let (n, m) = self.size;
+        if x.size() != m {
+            return Err(LinAlgError::OperationNonConforming {
+                operation: "Sizes not conform to multiplication"
+                    .to_string(),
+            });
+        }
+        let x = &x.matrix.elements;
+        let mut to_return = Vec::with_capacity(n);
+        for i in 1..=n {
+            let mut acc = K::default();
+            for k in self.row_range(i) {
+                acc = acc
+                    + self.values[k]
+                        * x[self.col_idx[k] - 1];
+            }
+            to_return.push(acc);
+        }
+        Vector::new(to_return)
+    }
+
+    // Transpose by counting the entries of each column
+    pub fn tr(&self) -> Result<CsrMatrix<K>, LinAlgError> {
+        let (n, m) = self.size;
+        let mut row_ptr = vec![0; m + 1];
+        for &j in &self.col_idx {
+            row_ptr[j] += 1;
+        }
+        for j in 1..=m {
+            row_ptr[j] += row_ptr[j - 1];
+        }
+        // walking the rows in order keeps each new row sorted
+        let mut next = row_ptr.clone();
+        let mut col_idx = vec![0; self.nnz()];
+        let mut values = vec![K::default(); self.nnz()];
+        for i in 1..=n {
+            for k in self.row_range(i) {
+                let j = self.col_idx[k];
+                col_idx[next[j - 1]] = i;
+                values[next[j - 1]] = self.values[k];
+                next[j - 1] += 1;
+            }
+        }
+        Ok(CsrMatrix {
+            size: (m, n),
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    // Add a sparse matrix of the same dimensions, merging the rows
+    pub fn add(
+        &self,
+        other: &CsrMatrix<K>,
+    ) -> Result<CsrMatrix<K>, LinAlgError> {
+        if self.size != other.size {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "Sparse Addition"
+                        .to_string(),
+                },
+            );
+        }
+        let (n, _) = self.size;
+        let mut row_ptr = Vec::with_capacity(n + 1);
+        let mut col_idx = Vec::with_capacity(self.nnz());
+        let mut values = Vec::with_capacity(self.nnz());
+        row_ptr.push(0);
+        for i in 1..=n {
+            let (mut a, a_end) =
+                (self.row_ptr[i - 1], self.row_ptr[i]);
+            let (mut b, b_end) =
+                (other.row_ptr[i - 1], other.row_ptr[i]);
+            while a < a_end || b < b_end {
+                let ja = self
+                    .col_idx
+                    .get(a)
+                    .filter(|_| a < a_end);
+                let jb = other
+                    .col_idx
+                    .get(b)
+                    .filter(|_| b < b_end);
+                match (ja, jb) {
+                    (Some(&ja), Some(&jb)) if ja == jb => {
+                        col_idx.push(ja);
+                        values.push(
+                            self.values[a]
+                                + other.values[b],
+                        );
+                        a += 1;
+                        b += 1;
+                    }
+                    (Some(&ja), Some(&jb)) if ja < jb => {
+                        col_idx.push(ja);
+                        values.push(self.values[a]);
+                        a += 1;
+                    }
+                    (Some(&ja), None) => {
+                        col_idx.push(ja);
+                        values.push(self.values[a]);
+                        a += 1;
+                    }
+                    (_, Some(&jb)) => {
+                        col_idx.push(jb);
+                        values.push(other.values[b]);
+                        b += 1;
+                    }
+                    (None, None) => break,
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        Ok(CsrMatrix {
+            size: self.size,
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    // Scale the matrix by a factor
+    pub fn scl(
+        &self,
+        scaling: K,
+    ) -> Result<CsrMatrix<K>, LinAlgError> {
+        Ok(CsrMatrix {
+            size: self.size,
+            row_ptr: self.row_ptr.clone(),
+            col_idx: self.col_idx.clone(),
+            values: self
+                .values
+                .iter()
+                .map(|x| scaling * *x)
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,8 @@
+// Sparse matrices that only store their nonzero entries
+mod coo;
+mod csc;
+mod csr;
+
+pub use coo::CooBuilder;
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
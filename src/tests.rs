@@ -1,17 +1,31 @@
-use crate::complex::ComplexNumber;
-use crate::errors::LinAlgError;
-use crate::matrix::Matrix;
-use crate::traits::Conj;
-use crate::vector::Vector;
+use ft_matrix::complex::ComplexNumber;
+use ft_matrix::errors::LinAlgError;
+use ft_matrix::gemm::gemm;
+use ft_matrix::io::csv::{CsvOptions, MissingValues};
+use ft_matrix::io::matrix_market::{
+    read_mtx, write_mtx, write_mtx_sparse,
+};
+use ft_matrix::io::npy::{read_npy, write_npy};
+use ft_matrix::io::npz::{read_npz, write_npz};
+use ft_matrix::matrix::Matrix;
+use ft_matrix::solvers::bicgstab::bicgstab;
+use ft_matrix::solvers::cg::cg;
+use ft_matrix::solvers::gmres::gmres;
+use ft_matrix::solvers::{
+    Identity, IncompleteCholesky, Jacobi, SolverOptions,
+};
+use ft_matrix::sparse::{CooBuilder, CscMatrix, CsrMatrix};
+use ft_matrix::traits::Conj;
+use ft_matrix::vector::Vector;
 
-// Defines a series of test functions to validate matrix and vector operations 
+// Defines a series of test functions to validate matrix and vector operations
 // and their interactions, especially focusing on error handling and complex number manipulations.
 pub fn old_test() -> Result<(), LinAlgError> {
     {
         println!(
             "{}",
             Matrix::<f64>::projection(
-                80. * 3.14 / 180.,
+                80. * std::f64::consts::PI / 180.,
                 1.777,
                 0.1,
                 1000.
@@ -24,11 +38,7 @@ pub fn old_test() -> Result<(), LinAlgError> {
         println!("Complex number printing test");
         let x =
             ComplexNumber::Cartesian { re: 1.0, im: -1.0 };
-        println!(
-            "{} {}",
-            x.clone() * x.conj(),
-            x.clone() * x.inv()
-        );
+        println!("{} {}", x * x.conj(), x * x.inv());
         println!("Its real part is {}", x.re());
         println!("Its complex part is {}", x.im());
     }
@@ -782,7 +792,7 @@ pub fn ex14_test() -> Result<(), LinAlgError> {
     println!(
         "{}",
         Matrix::<f64>::projection(
-            80. * 3.14 / 180.,
+            80. * std::f64::consts::PI / 180.,
             1.777,
             0.1,
             1000.
@@ -798,8 +808,8 @@ pub fn ex15_test() -> Result<(), LinAlgError> {
     println!("the number {}", x);
     println!(
         "Norm: {} Multiplied by inv {}",
-        x.clone() * x.conj(),
-        x.clone() * x.inv()
+        x * x.conj(),
+        x * x.inv()
     );
     println!("Its real part is {}", x.re());
     println!("Its complex part is {}", x.im());
@@ -917,9 +927,1526 @@ pub fn basic_stats_test() -> Result<(), LinAlgError> {
      {mean_v} and standard deviation {std_v}"
     );
     println!(
-        "Also 42 42 42 for the lolz {}",
-        Matrix::<f64>::ones(42, 42)?.scl(42.)?
+        "Also 42 42 42 for the lolz\n{}",
+        Matrix::<f64>::ones(42, 42)?.scl(42.)?.pretty()
+    );
+
+    Ok(())
+}
+
+// Factorizes a matrix once with partial pivoting and reuses the factors
+// for the determinant, rank, inverse and solving a system.
+pub fn lu_test() -> Result<(), LinAlgError> {
+    let u = Matrix::new(vec![1e-20, 1., 1., 1.], 2, 2)?;
+    let lu = u.lu()?;
+    println!("P {} L {} U {}", lu.p()?, lu.l(), lu.u());
+    println!("P·A {}", lu.p()?.mul_mat(&u)?);
+    println!("L·U {}", lu.l().mul_mat(lu.u())?);
+    let b = Vector::new(vec![1., 2.])?;
+    println!("{}", lu.solve(&b)?);
+    println!("// [1., 1.]");
+    let u = Matrix::new(
+        vec![8., 5., -2., 4., 7., 20., 7., 6., 1.],
+        3,
+        3,
+    )?;
+    let lu = u.lu()?;
+    println!("{} {}", lu.determinant()?, lu.rank());
+    println!("// -174.0 3");
+    println!("{}", lu.inverse()?);
+    println!(
+        "// [0.649425287, 0.097701149, -0.655172414]
+                    // [-0.781609195, -0.126436782, 0.965517241]
+                    // [0.143678161, 0.074712644, -0.206896552]"
+    );
+    Ok(())
+}
+
+// Solves linear systems directly for real, single precision and complex
+// matrices, with one or several right hand sides.
+pub fn solve_test() -> Result<(), LinAlgError> {
+    let a = Matrix::new(
+        vec![2., 1., 1., 1., 3., 2., 1., 0., 0.],
+        3,
+        3,
+    )?;
+    let b = Vector::new(vec![4., 5., 6.])?;
+    println!("{}", a.solve(&b)?);
+    println!("// [8., -1., -11.]");
+    let a32 =
+        Matrix::<f32>::new(vec![4., 1., 1., 3.], 2, 2)?;
+    let b32 = Vector::<f32>::new(vec![1., 2.])?;
+    println!("{}", a32.solve(&b32)?);
+    println!("// [0.0909091, 0.6363636]");
+    let rhs =
+        Matrix::new(vec![4., 5., 6., 1., 0., 0.], 3, 2)?;
+    println!("{}", a.solve_mat(&rhs)?);
+    println!("// [[8., -1., -11.], [0., 0., 1.]]");
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(1., 1.),
+            ComplexNumber::n(0., -1.),
+            ComplexNumber::n(2., 0.),
+            ComplexNumber::n(1., 2.),
+        ],
+        2,
+        2,
+    )?;
+    let x = Vector::new(vec![
+        ComplexNumber::n(1., 0.),
+        ComplexNumber::n(0., 1.),
+    ])?;
+    println!("{}", c.solve(&c.mul_vec(&x)?)?);
+    println!("// [1 + 0i, 0 + 1i]");
+    let singular = Matrix::new(vec![1., 2., 2., 4.], 2, 2)?;
+    match singular.solve(&Vector::new(vec![1., 1.])?) {
+        Ok(x) => println!("{}", x),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Operation not defined for a Singular Matrix"
+    );
+    Ok(())
+}
+
+// Checks the Householder QR factorization on a tall real matrix and a
+// complex one, where Q must be unitary.
+pub fn qr_test() -> Result<(), LinAlgError> {
+    let a = Matrix::new(
+        vec![12., 6., -4., -51., 167., 24., 4., -68., -41.],
+        3,
+        3,
+    )?;
+    let qr = a.qr()?;
+    println!("Q {} R {}", qr.q(), qr.r());
+    println!(
+        "// R [[-14, 0, 0], [-21, -175, 0], [14, 70, -35]]"
+    );
+    println!("Q·R {}", qr.q().mul_mat(qr.r())?);
+    let tall = Matrix::new(
+        vec![1., 1., 1., 1., 1., 2., 3., 4.],
+        4,
+        2,
+    )?;
+    let full = tall.qr()?;
+    let thin = tall.qr_economy()?;
+    println!(
+        "full sizes {:?} {:?} economy sizes {:?} {:?}",
+        full.q().size(),
+        full.r().size(),
+        thin.q().size(),
+        thin.r().size()
+    );
+    println!("Q·R {}", thin.q().mul_mat(thin.r())?);
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(1., 1.),
+            ComplexNumber::n(0., 2.),
+            ComplexNumber::n(3., 0.),
+            ComplexNumber::n(2., -1.),
+            ComplexNumber::n(1., 0.),
+            ComplexNumber::n(0., 1.),
+        ],
+        3,
+        2,
+    )?;
+    let qr = c.qr()?;
+    println!("Qᴴ·Q {}", qr.q().adj()?.mul_mat(qr.q())?);
+    println!("Q·R {}", qr.q().mul_mat(qr.r())?);
+    println!("A   {}", c);
+    Ok(())
+}
+
+// Factorizes the covariance of normalized data with Cholesky and uses the
+// factor to solve a system and compute the log determinant.
+pub fn cholesky_test() -> Result<(), LinAlgError> {
+    let u = Matrix::new(
+        vec![
+            8., 4., 7., 21., 5., 7., 6., 18., -2., 20., 1.,
+            7.,
+        ],
+        4,
+        3,
+    )?;
+    let (u2, _) = u.normalize_cols()?;
+    let cov = u2.tr()?.mul_mat(&u2)?.scl(1. / 4.)?;
+    let chol = cov.cholesky()?;
+    println!("L {}", chol.l());
+    println!(
+        "L·Lᴴ {}",
+        chol.l().mul_mat(&chol.l().adj()?)?
+    );
+    println!("A   {}", cov);
+    let b = Vector::new(vec![1., 2., 3.])?;
+    println!("{}", chol.solve(&b)?);
+    println!("{}", cov.solve(&b)?);
+    println!(
+        "log det {} det {}",
+        chol.log_determinant()?,
+        cov.determinant()?.ln()
+    );
+    let not_pd = Matrix::new(vec![1., 2., 2., 1.], 2, 2)?;
+    match not_pd.cholesky() {
+        Ok(c) => println!("{}", c.l()),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Matrix is not positive definite");
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(4., 0.),
+            ComplexNumber::n(1., 1.),
+            ComplexNumber::n(1., -1.),
+            ComplexNumber::n(3., 0.),
+        ],
+        2,
+        2,
+    )?;
+    let chol = c.cholesky()?;
+    println!(
+        "L·Lᴴ {}",
+        chol.l().mul_mat(&chol.l().adj()?)?
+    );
+    println!("A   {}", c);
+    Ok(())
+}
+
+// Fits a straight line by least squares and shows the effective rank
+// reported for a design matrix with a repeated column.
+pub fn lstsq_test() -> Result<(), LinAlgError> {
+    let x = Matrix::new(
+        vec![1., 1., 1., 1., 1., 1., 2., 3., 4., 5.],
+        5,
+        2,
+    )?;
+    let y = Vector::new(vec![2.1, 3.9, 6.2, 7.8, 10.1])?;
+    let fit = x.lstsq(&y)?;
+    println!(
+        "coefs {} residual {} rank {}",
+        fit.solution, fit.residual_norm, fit.rank
+    );
+    println!("// coefs [0.05,1.99] residual 0.3271 rank 2");
+    let exact = Vector::new(vec![3., 5., 7., 9., 11.])?;
+    let fit = x.lstsq(&exact)?;
+    println!(
+        "coefs {} residual {} rank {}",
+        fit.solution, fit.residual_norm, fit.rank
+    );
+    println!("// coefs [1,2] residual 0 rank 2");
+    let repeated = x.append_horizontal(&x)?;
+    let fit = repeated.lstsq(&y)?;
+    println!(
+        "coefs {} residual {} rank {}",
+        fit.solution, fit.residual_norm, fit.rank
+    );
+    println!("// residual 0.3271 rank 2");
+    // in f32 the second column is only a multiple of the first up to f32
+    // round-off, which still counts as rank deficient
+    let single = Matrix::new(
+        vec![0.1f32, 0.2, 0.3, 0.4, 0.3, 0.6, 0.9, 1.2],
+        4,
+        2,
+    )?;
+    let fit = single
+        .lstsq(&Vector::new(vec![1., 0., 1., 0.])?)?;
+    println!(
+        "coefs {} residual {} rank {}",
+        fit.solution, fit.residual_norm, fit.rank
+    );
+    println!(
+        "// coefs [0,0.4444445] residual 1.2110602 rank 1"
+    );
+    let double = Matrix::new(
+        vec![0.1, 0.2, 0.3, 0.4, 0.3, 0.6, 0.9, 1.2],
+        4,
+        2,
+    )?;
+    let fit = double
+        .lstsq(&Vector::new(vec![1., 0., 1., 0.])?)?;
+    println!(
+        "coefs {} residual {} rank {}",
+        fit.solution, fit.residual_norm, fit.rank
+    );
+    println!(
+        "// coefs [0,0.4444444] residual 1.2110601 rank 1"
+    );
+    Ok(())
+}
+
+// Diagonalizes a real symmetric matrix and a complex Hermitian one,
+// checking that A·V = V·Λ.
+pub fn eigh_test() -> Result<(), LinAlgError> {
+    let a = Matrix::new(
+        vec![2., -1., 0., -1., 2., -1., 0., -1., 2.],
+        3,
+        3,
+    )?;
+    let eig = a.eigh()?;
+    println!("{}", eig.eigenvalues());
+    println!("// [0.585786438, 2., 3.414213562]");
+    let v = eig.eigenvectors();
+    let lambda = Matrix::diag(
+        (1..=3)
+            .map(|i| eig.eigenvalues().el(i))
+            .collect::<Result<Vec<f64>, LinAlgError>>()?,
+    )?;
+    println!("A·V {}", a.mul_mat(v)?);
+    println!("V·Λ {}", v.mul_mat(&lambda)?);
+    println!("Vᵀ·V {}", v.tr()?.mul_mat(v)?);
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(2., 0.),
+            ComplexNumber::n(0., 1.),
+            ComplexNumber::n(0., -1.),
+            ComplexNumber::n(2., 0.),
+        ],
+        2,
+        2,
+    )?;
+    let eig = c.eigh()?;
+    println!("{}", eig.eigenvalues());
+    println!("// [1., 3.]");
+    let v = eig.eigenvectors();
+    println!(
+        "Vᴴ·A·V {}",
+        v.adj()?.mul_mat(&c)?.mul_mat(v)?
+    );
+    let not_symmetric =
+        Matrix::new(vec![1., 2., 3., 4.], 2, 2)?;
+    match not_symmetric.eigh() {
+        Ok(e) => println!("{}", e.eigenvalues()),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Matrix is not Hermitian");
+    // f32 stops at its own round-off and agrees with f64 to it
+    let values = vec![
+        4., 1., 0.5, 0.2, 1., 3., 0.3, 0.1, 0.5, 0.3, 2.,
+        0.4, 0.2, 0.1, 0.4, 1.,
+    ];
+    let single = Matrix::new(
+        values.iter().map(|x| *x as f32).collect(),
+        4,
+        4,
+    )?
+    .eigh()?;
+    let double = Matrix::new(values, 4, 4)?.eigh()?;
+    println!("{}", single.eigenvalues());
+    let mut worst: f64 = 0.0;
+    for i in 1..=4 {
+        worst = worst.max(
+            (single.eigenvalues().el(i)?
+                - double.eigenvalues().el(i)?)
+            .abs(),
+        );
+    }
+    println!("{}", worst < 1e-5);
+    println!("// [0.8594248,1.9916335,2.3826025,4.766339] to about 1e-6");
+    println!("// true");
+    Ok(())
+}
+
+// Computes the complex eigenvalues of non-symmetric real matrices and
+// checks the right eigenvectors through A·v = λ·v.
+pub fn eig_test() -> Result<(), LinAlgError> {
+    let rotation =
+        Matrix::new(vec![0., 1., -1., 0.], 2, 2)?;
+    println!("{}", rotation.eig()?);
+    println!("// [0 + 1i,0 + -1i]");
+    let a = Matrix::new(
+        vec![
+            2., 1., 0., 0., 0., 2., 1., 0., 0., 0., 3., 4.,
+            5., 0., -1., 1.,
+        ],
+        4,
+        4,
+    )?;
+    let (values, vectors) = a.eig_vectors()?;
+    println!("{}", values);
+    let complex = Matrix::new(
+        a.elements
+            .iter()
+            .map(|x| ComplexNumber::n(*x, 0.))
+            .collect(),
+        4,
+        4,
+    )?;
+    for j in 1..=4 {
+        let v = vectors.column_extract(j)?;
+        let residual = complex
+            .mul_vec(&v)?
+            .sub(&v.scl(values.el(j)?)?)?;
+        println!(
+            "λ = {} ‖A·v − λ·v‖ = {}",
+            values.el(j)?,
+            residual.norm()
+        );
+    }
+    let sum = (1..=4)
+        .try_fold(ComplexNumber::n(0., 0.), |acc, i| {
+            Ok::<_, LinAlgError>(acc + values.el(i)?)
+        })?;
+    println!("sum {} trace {}", sum, a.trace()?);
+    // a repeated eigenvalue still gets independent eigenvectors
+    let (_, vectors) =
+        Matrix::<f64>::identity(2)?.eig_vectors()?;
+    println!("{}", vectors);
+    println!("// [[-0.7071067811865475 + -0i,-0.7071067811865475 + -0i],[0.7071067811865476 + 0i,-0.7071067811865475 + 0i]]");
+    let repeated = Matrix::new(
+        vec![2., 0., 0., 0., 2., 0., 1., 1., 3.],
+        3,
+        3,
+    )?;
+    let (values, vectors) = repeated.eig_vectors()?;
+    let complex = Matrix::new(
+        repeated
+            .elements
+            .iter()
+            .map(|x| ComplexNumber::n(*x, 0.))
+            .collect(),
+        3,
+        3,
+    )?;
+    for j in 1..=3 {
+        let v = vectors.column_extract(j)?;
+        let residual = complex
+            .mul_vec(&v)?
+            .sub(&v.scl(values.el(j)?)?)?;
+        println!(
+            "λ = {} ‖A·v − λ·v‖ = {}",
+            values.el(j)?,
+            residual.norm()
+        );
+    }
+    println!("det V {}", vectors.determinant()?);
+    println!("// det V -0.5773502691907798 + 0i");
+    Ok(())
+}
+
+// Computes singular value decompositions and the quantities built on
+// them: pseudo-inverse, numerical rank, condition number and 2-norm.
+pub fn svd_test() -> Result<(), LinAlgError> {
+    let a =
+        Matrix::new(vec![3., 2., 2., 3., -2., 2.], 2, 3)?;
+    let svd = a.svd()?;
+    println!("{}", svd.singular_values());
+    println!("// [5., 3.]");
+    let sigma = Matrix::diag(vec![5., 3.])?;
+    println!(
+        "U·Σ·Vᴴ {}",
+        svd.u().mul_mat(&sigma)?.mul_mat(svd.v_h())?
+    );
+    println!("A     {}", a);
+    println!(
+        "A·A⁺·A {}",
+        a.mul_mat(&a.pinv()?)?.mul_mat(&a)?
+    );
+    println!("‖A‖₂ {} cond {}", a.norm_2()?, a.cond()?);
+    println!("// ‖A‖₂ 5 cond 1.6666667");
+    let nearly = Matrix::new(
+        vec![
+            1.,
+            2.,
+            3.,
+            2.,
+            4.,
+            6.0000000000001,
+            1.,
+            0.,
+            1.,
+        ],
+        3,
+        3,
+    )?;
+    println!(
+        "rank {} numerical rank {}",
+        nearly.rank()?,
+        nearly.numerical_rank(1e-10)?
+    );
+    println!("// rank 3 numerical rank 2");
+    let zero = Matrix::<f64>::zero(3, 2)?;
+    let svd = zero.svd()?;
+    println!(
+        "{} Uᵀ·U {}",
+        svd.singular_values(),
+        svd.u().tr()?.mul_mat(svd.u())?
+    );
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(1., 1.),
+            ComplexNumber::n(0., 2.),
+            ComplexNumber::n(3., 0.),
+            ComplexNumber::n(2., -1.),
+            ComplexNumber::n(1., 0.),
+            ComplexNumber::n(0., 1.),
+        ],
+        3,
+        2,
+    )?;
+    let svd = c.svd()?;
+    let s = svd.singular_values();
+    let sigma = Matrix::diag(vec![
+        ComplexNumber::n(s.el(1)?, 0.),
+        ComplexNumber::n(s.el(2)?, 0.),
+    ])?;
+    println!(
+        "U·Σ·Vᴴ {}",
+        svd.u().mul_mat(&sigma)?.mul_mat(svd.v_h())?
+    );
+    println!("A     {}", c);
+    // f32 stops at its own round-off
+    let f =
+        Matrix::new(vec![3f32, 2., 2., 3., -2., 2.], 2, 3)?;
+    let svd = f.svd()?;
+    println!("{}", svd.singular_values());
+    println!("// [5., 3.] to about 1e-7");
+    println!(
+        "‖A‖₂ {} cond {} rank {}",
+        f.norm_2()?,
+        f.cond()?,
+        f.numerical_rank(1e-5)?
+    );
+    println!(
+        "A·A⁺·A {}",
+        f.mul_mat(&f.pinv()?)?.mul_mat(&f)?
     );
+    Ok(())
+}
 
+// Writes the same formulas with operators instead of chained fallible
+// methods.
+pub fn ops_test() -> Result<(), LinAlgError> {
+    let a = Matrix::new(vec![1., 2., 3., 4.], 2, 2)?;
+    let b = Matrix::new(vec![7., 4., -2., 2.], 2, 2)?;
+    let v = Vector::new(vec![1., -1.])?;
+    println!("{}", a.add(&b)?.scl(2.)?);
+    println!("{}", 2. * (&a + &b));
+    println!("{}", &a * &b - &b * &a);
+    println!("{}", a.mlt(&b)?.sub(&b.mlt(&a)?)?);
+    println!("{}", -&a * &v);
+    let mut c = a.clone();
+    c += &b;
+    c -= a;
+    c *= 0.5;
+    println!("{}", c);
+    println!("{}", b.scl(0.5)?);
+    let mut u = Vector::new(vec![2., 3.])?;
+    u += Vector::new(vec![5., 7.])?;
+    println!("{}", &u - &v * 3.);
+    println!("// [4.0, 13.0]");
+    let z = Vector::new(vec![
+        ComplexNumber::n(1., 1.),
+        ComplexNumber::n(0., -1.),
+    ])?;
+    println!("{}", ComplexNumber::n(0., 1.) * z);
+    println!("// [-1 + 1i, 1 + 0i]");
+    Ok(())
+}
+
+// Reads and writes entries with 1-based indexing and the 0-based
+// accessors, and shows that index 0 is now reported as out of bounds.
+pub fn index_test() -> Result<(), LinAlgError> {
+    let mut u = Matrix::new(vec![1., 2., 3., 4.], 2, 2)?;
+    println!("{} {}", u[(1, 2)], u.el(1, 2)?);
+    println!("// 3 3");
+    u[(2, 1)] = 20.;
+    if let Some(x) = u.get_mut(0, 0) {
+        *x = 10.;
+    }
+    println!("{} {:?} {:?}", u, u.get(1, 0), u.get(2, 0));
+    println!("// [[10,20],[3,4]] Some(20.0) None");
+    match u.el(0, 1) {
+        Ok(x) => println!("{}", x),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Trying to access (0,1) in a (2,2) element"
+    );
+    let mut v = Vector::new(vec![1., 2., 3.])?;
+    v[3] = v[1] + v[2];
+    println!("{} {:?}", v, v.get(2));
+    println!("// [1,2,3] Some(3.0)");
+    match v.set(0, 1.) {
+        Ok(_) => println!("{}", v),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Trying to access 0 in a 3 element");
+    Ok(())
+}
+
+pub fn views_test() -> Result<(), LinAlgError> {
+    let mut u = Matrix::new(
+        vec![1., 2., 3., 4., 5., 6., 7., 8., 9.],
+        3,
+        3,
+    )?;
+    println!("{} {}", u.row(2)?, u.col(3)?);
+    println!("// [[2],[5],[8]] [[7,8,9]]");
+    println!("{} {}", u.diagonal(), u.t().el(1, 3)?);
+    println!("// [[1,5,9]] 3");
+    let block = u.submatrix(2..=3, 2..)?;
+    println!("{} {}", block, block.t());
+    println!("// [[5,6],[8,9]] [[5,8],[6,9]]");
+    let id = Matrix::<f64>::identity(2)?;
+    println!(
+        "{} {}",
+        block.mlt(&id)?,
+        u.submatrix(..=2, ..=2)?.add(&block)?
+    );
+    println!("// [[5,6],[8,9]] [[6,8],[12,14]]");
+    println!("{}", id.mlt(&u.submatrix(1..=2, 3..=3)?)?);
+    println!("// [[7,8]]");
+    let x = block.solve(&Vector::new(vec![13., 15.])?)?;
+    println!("{}", x);
+    println!("// [1,1]");
+    u.diagonal_mut().fill(0.);
+    u.col_mut(1)?
+        .copy_from(&Vector::new(vec![-1., -2., -3.])?)?;
+    println!("{}", u);
+    println!("// [[-1,-2,-3],[4,0,6],[7,8,0]]");
+    match u.submatrix(0..2, ..) {
+        Ok(v) => println!("{}", v),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Trying to access (0,1) in a (3,3) element"
+    );
+    Ok(())
+}
+
+pub fn gemm_test() -> Result<(), LinAlgError> {
+    // awkward sizes so every block and tile has a ragged edge
+    let (m, k, n) = (131, 263, 70);
+    let fill = |rows: usize, cols: usize, seed: f64| {
+        Matrix::new(
+            (0..rows * cols)
+                .map(|x| {
+                    ((x as f64 + seed) * 0.37).sin() / 3.0
+                })
+                .collect(),
+            rows,
+            cols,
+        )
+    };
+    let u = fill(m, k, 1.0)?;
+    let v = fill(k, n, 2.0)?;
+    let mut naive = Matrix::<f64>::zero(m, n)?;
+    for i in 1..=m {
+        for j in 1..=n {
+            let mut acc = 0.0;
+            for w in 1..=k {
+                acc += u.el(i, w)? * v.el(w, j)?;
+            }
+            naive.set(i, j, acc)?;
+        }
+    }
+    println!("{}", u.mlt(&v)? == naive);
+    println!("// true");
+    let ut = u.t().to_matrix()?;
+    println!("{}", ut.t().mlt(&v)? == naive);
+    println!("// true");
+
+    let a = Matrix::new(vec![1., 3., 2., 4.], 2, 2)?;
+    let b = Matrix::new(vec![5., 7., 6., 8.], 2, 2)?;
+    let mut c = Matrix::<f64>::identity(2)?;
+    gemm(2., &a, &b, 10., &mut c)?;
+    println!("{}", c);
+    println!("// [[48,86],[44,110]]");
+    match gemm(1., &a.t(), &b.col(1)?, 0., &mut c) {
+        Ok(_) => println!("{}", c),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Sizes not conform to multiplication");
+    Ok(())
+}
+
+pub fn parallel_test() -> Result<(), LinAlgError> {
+    let n = 150;
+    let u = Matrix::new(
+        (0..n * n)
+            .map(|x| ((x * 7 % 23) as f64) - 11.0)
+            .collect(),
+        n,
+        n,
+    )?;
+    let run = || -> Result<_, LinAlgError> {
+        Ok((
+            u.mlt(&u)?,
+            u.add(&u.tr()?)?,
+            u.scl(0.5)?.sub(&u)?,
+            u.lu()?.u().clone(),
+        ))
+    };
+    #[cfg(feature = "parallel")]
+    {
+        use ft_matrix::parallel;
+        parallel::set_threshold(usize::MAX);
+        let serial = run()?;
+        parallel::set_threshold(0);
+        parallel::set_num_threads(3);
+        println!("{}", run()? == serial);
+        println!("// true");
+        parallel::set_num_threads(0);
+        parallel::set_threshold(1 << 18);
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        let serial = run()?;
+        println!("{}", run()? == serial);
+        println!(
+            "// true (built without the parallel feature)"
+        );
+    }
+    Ok(())
+}
+
+pub fn simd_test() -> Result<(), LinAlgError> {
+    // lengths 1 to 39 exercise both the vector body and the scalar tail
+    let mut worst: f64 = 0.0;
+    for n in 1..40 {
+        let x: Vec<f64> = (0..n)
+            .map(|i| ((i * 13 % 7) as f64) - 3.5)
+            .collect();
+        let y: Vec<f64> =
+            (0..n).map(|i| (i as f64).cos()).collect();
+        let (mut dot, mut sum, mut max) = (0.0, 0.0, 0.0);
+        for (a, b) in x.iter().zip(&y) {
+            dot += a * b;
+            sum += a.abs();
+            max = f64::max(max, a.abs());
+        }
+        let u = Vector::new(x.clone())?;
+        let v = Vector::new(y)?;
+        let single = Vector::new(
+            x.iter().map(|a| *a as f32).collect(),
+        )?;
+        worst = worst
+            .max((u.dot(&v)? - dot).abs())
+            .max((u.norm() - u.dot(&u)?.sqrt()).abs())
+            .max((u.norm_1() - sum).abs())
+            .max((u.norm_inf() - max).abs())
+            .max((single.norm_1() - sum).abs())
+            .max((single.norm_inf() - max).abs());
+    }
+    println!("{}", worst < 1e-12);
+    println!("// true");
+    let w = Vector::new(vec![1., f64::NAN, -3., 2., 0.5])?;
+    println!("{} {}", w.norm_inf(), w.norm_1().is_nan());
+    println!("// 3 true");
+    let c = Vector::linear_combination(
+        &[
+            &Vector::new(vec![1.0f32; 11])?,
+            &Vector::new(vec![2.0f32; 11])?,
+        ],
+        &[0.5, -0.25],
+    )?;
+    println!("{}", c.norm_inf());
+    println!("// 0");
+    Ok(())
+}
+
+pub fn inplace_test() -> Result<(), LinAlgError> {
+    let mut u =
+        Matrix::new(vec![1., 2., 3., 4., 5., 6.], 2, 3)?;
+    let v =
+        Matrix::new(vec![6., 5., 4., 3., 2., 1.], 2, 3)?;
+    let reference = u.add(&v)?.scl(2.)?.sub(&v)?;
+    u.add_assign(&v)?;
+    u.scale_mut(2.)?;
+    u.sub_assign(&v)?;
+    println!("{} {}", u, u == reference);
+    println!("// [[8,9],[10,11],[12,13]] true");
+    u.axpy(-2., &v.submatrix(.., ..)?)?;
+    println!("{}", u);
+    println!("// [[-4,-1],[2,5],[8,11]]");
+    u.transpose_in_place()?;
+    println!("{} {:?}", u, u.size());
+    println!("// [[-4,2,8],[-1,5,11]] (3, 2)");
+
+    // one output buffer reused across sizes and operations
+    let mut out = Matrix::<f64>::zero(0, 0)?;
+    u.tr_into(&mut out)?;
+    println!("{}", out);
+    println!("// [[-4,-1],[2,5],[8,11]]");
+    v.t().to_matrix()?.mlt_into(&v, &mut out)?;
+    println!("{}", out == v.tr()?.mlt(&v)?);
+    println!("// true");
+    v.sub_into(&v, &mut out)?;
+    println!("{}", out);
+    println!("// [[0,0],[0,0],[0,0]]");
+
+    let mut w = Vector::new(vec![1., 2., 3.])?;
+    let mut x = Vector::new(vec![0.])?;
+    w.scl_into(3., &mut x)?;
+    x.add_assign(&w)?;
+    w.axpy(-4., &x)?;
+    println!("{} {} {}", x, w, x.size());
+    println!("// [4,8,12] [-15,-30,-45] 3");
+    match w.add_assign(&Vector::new(vec![1.])?) {
+        Ok(_) => println!("{}", w),
+        Err(e) => println!("{}", e),
+    }
+    println!("// sum: Nonconforming dimentions");
+
+    // cycle following on a tall matrix against the copying transpose
+    let tall = Matrix::new(
+        (0..35).map(|x| x as f64).collect(),
+        7,
+        5,
+    )?;
+    let mut moved = tall.clone();
+    moved.transpose_in_place()?;
+    println!("{}", moved == tall.tr()?);
+    println!("// true");
+    Ok(())
+}
+
+pub fn sparse_test() -> Result<(), LinAlgError> {
+    // the (1, 1) entry is given twice and summed
+    let a = CsrMatrix::from_triplets(
+        3,
+        4,
+        &[
+            (1, 1, 2.),
+            (3, 4, 5.),
+            (2, 2, -1.),
+            (1, 3, 4.),
+            (1, 1, 1.),
+        ],
+    )?;
+    println!("{} {}", a.nnz(), a.to_matrix()?);
+    println!("// 4 [[3,0,0],[0,-1,0],[4,0,0],[0,0,5]]");
+    println!("{:?} {:?}", a.row_ptr(), a.col_indices());
+    println!("// [0, 2, 3, 4] [1, 3, 2, 4]");
+    let x = Vector::new(vec![1., 2., 3., 4.])?;
+    println!(
+        "{} {}",
+        a.mul_vec(&x)?,
+        a.to_matrix()?.mul_vec(&x)?
+    );
+    println!("// [15,-2,20] [15,-2,20]");
+    let c = a.to_csc()?;
+    println!(
+        "{:?} {:?} {}",
+        c.col_ptr(),
+        c.row_indices(),
+        c.mul_vec(&x)?
+    );
+    println!("// [0, 1, 2, 3, 4] [1, 2, 1, 3] [15,-2,20]");
+    println!(
+        "{} {}",
+        a.tr()?.to_matrix()? == a.to_matrix()?.tr()?,
+        c.tr()?.to_csr()? == a.tr()?
+    );
+    println!("// true true");
+    let b = CsrMatrix::from_matrix(&Matrix::new(
+        vec![
+            0., 1., 0., 0., 1., 0., 0., 0., 0., 0., 0., -5.,
+        ],
+        3,
+        4,
+    )?)?;
+    let sum = a.add(&b)?.scl(2.)?;
+    println!(
+        "{} {} {}",
+        sum.nnz(),
+        sum.to_matrix()?,
+        sum.el(3, 4)?
+    );
+    println!("// 5 [[6,2,0],[0,0,0],[8,0,0],[0,0,0]] 0");
+    println!(
+        "{}",
+        c.add(&b.to_csc()?)?.scl(2.)?.to_matrix()?
+            == sum.to_matrix()?
+    );
+    println!("// true");
+    match a.add(&a.tr()?) {
+        Ok(s) => println!("{}", s.nnz()),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Sparse Addition: Nonconforming dimentions"
+    );
+    match CscMatrix::from_triplets(2, 2, &[(3, 1, 1.)]) {
+        Ok(s) => println!("{}", s.nnz()),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Trying to access (3,1) in a (2,2) element"
+    );
+    Ok(())
+}
+
+pub fn coo_test() -> Result<(), LinAlgError> {
+    // assemble a 1D Laplacian from 2 x 2 element matrices
+    let elements = 4;
+    let mut builder =
+        CooBuilder::new(elements + 1, elements + 1);
+    for e in 1..=elements {
+        for (a, b, v) in [
+            (0, 0, 1.),
+            (0, 1, -1.),
+            (1, 0, -1.),
+            (1, 1, 1.),
+        ] {
+            builder.push(e + a, e + b, v)?;
+        }
+    }
+    let csr = builder.to_csr()?;
+    println!("{} {}", builder.len(), csr.nnz());
+    println!("// 16 13");
+    println!("{}", csr.to_matrix()?);
+    println!("// [[1,-1,0,0,0],[-1,2,-1,0,0],[0,-1,2,-1,0],[0,0,-1,2,-1],[0,0,0,-1,1]]");
+    println!(
+        "{} {}",
+        builder.to_matrix()? == csr.to_matrix()?,
+        builder.to_csc()?.to_csr()? == csr
+    );
+    println!("// true true");
+    match builder.push(6, 1, 1.) {
+        Ok(_) => println!("{}", builder.len()),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Trying to access (6,1) in a (5,5) element"
+    );
+    Ok(())
+}
+
+// Conjugate gradient on a sparse symmetric positive definite system,
+// with and without preconditioning
+pub fn cg_test() -> Result<(), LinAlgError> {
+    // 1D Laplacian plus a growing diagonal
+    let n = 50;
+    let mut builder = CooBuilder::new(n, n);
+    for i in 1..=n {
+        builder.push(i, i, 2. + i as f64)?;
+        if i > 1 {
+            builder.push(i, i - 1, -1.)?;
+            builder.push(i - 1, i, -1.)?;
+        }
+    }
+    let a = builder.to_csr()?;
+    let b = Vector::new(vec![1.; n])?;
+    let options = SolverOptions::default();
+    let exact = a.to_matrix()?.solve(&b)?;
+
+    let (x, report) =
+        cg(&a, &b, None, &Identity, &options)?;
+    println!(
+        "{} {} {}",
+        report.converged,
+        report.iterations,
+        x.sub(&exact)?.norm() < 1e-8
+    );
+    println!("// true 37 true");
+    let jacobi = Jacobi::new(&a)?;
+    let (x, report) = cg(&a, &b, None, &jacobi, &options)?;
+    println!(
+        "{} {} {}",
+        report.converged,
+        report.iterations,
+        x.sub(&exact)?.norm() < 1e-8
+    );
+    println!("// true 12 true");
+    // tridiagonal, so IC(0) is the exact Cholesky factor
+    let ic = IncompleteCholesky::new(&a)?;
+    let (x, report) = cg(&a, &b, None, &ic, &options)?;
+    println!(
+        "{} {} {}",
+        report.converged,
+        report.iterations,
+        x.sub(&exact)?.norm() < 1e-8
+    );
+    println!("// true 1 true");
+
+    // a dense matrix is also an operator
+    let dense = Matrix::new(vec![4., 1., 1., 3.], 2, 2)?;
+    let (x, report) = cg(
+        &dense,
+        &Vector::new(vec![1., 2.])?,
+        None,
+        &Identity,
+        &options,
+    )?;
+    println!("{} {}", x, report.iterations);
+    println!("// [0.0909091, 0.6363636] 2");
+    let short = SolverOptions {
+        tol: 1e-10,
+        max_iterations: 3,
+    };
+    let (_, report) = cg(&a, &b, None, &Identity, &short)?;
+    println!("{} {}", report.converged, report.iterations);
+    println!("// false 3");
+    match cg(
+        &a,
+        &Vector::new(vec![1., 2.])?,
+        None,
+        &Identity,
+        &options,
+    ) {
+        Ok((x, _)) => println!("{}", x),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Not a square system. Can't solve it.: Nonconforming dimentions");
+    Ok(())
+}
+
+// GMRES and BiCGSTAB on a non-symmetric advection-diffusion system
+pub fn krylov_test() -> Result<(), LinAlgError> {
+    // upwinded 1D advection-diffusion
+    let n = 40;
+    let mut builder = CooBuilder::new(n, n);
+    for i in 1..=n {
+        builder.push(i, i, 3.)?;
+        if i > 1 {
+            builder.push(i, i - 1, -2.)?;
+            builder.push(i - 1, i, -0.5)?;
+        }
+    }
+    let a = builder.to_csr()?;
+    let b = Vector::new(vec![1.; n])?;
+    let options = SolverOptions::default();
+    let exact = a.to_matrix()?.solve(&b)?;
+
+    let (x, report) =
+        gmres(&a, &b, None, &Identity, 10, &options)?;
+    println!(
+        "{} {} {}",
+        report.converged,
+        report.iterations,
+        x.sub(&exact)?.norm() < 1e-8
+    );
+    println!("// true 67 true");
+    let jacobi = Jacobi::new(&a)?;
+    let (x, report) =
+        gmres(&a, &b, None, &jacobi, 40, &options)?;
+    println!(
+        "{} {} {}",
+        report.converged,
+        report.iterations,
+        x.sub(&exact)?.norm() < 1e-8
+    );
+    println!("// true 40 true");
+    let (x, report) =
+        bicgstab(&a, &b, None, &Identity, &options)?;
+    println!(
+        "{} {} {}",
+        report.converged,
+        report.iterations,
+        x.sub(&exact)?.norm() < 1e-8
+    );
+    println!("// true 40 true");
+    // the history starts at ‖b − A·x0‖ / ‖b‖ = 1 and ends at the reported residual
+    println!(
+        "{} {} {}",
+        report.residual_history.len(),
+        report.residual_history[0],
+        report.residual_history[report.iterations]
+            == report.relative_residual
+    );
+    println!("// 41 1 true");
+
+    // complex systems work the same way
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(2., 1.),
+            ComplexNumber::n(0., -1.),
+            ComplexNumber::n(1., 0.),
+            ComplexNumber::n(3., 0.),
+        ],
+        2,
+        2,
+    )?;
+    let x = Vector::new(vec![
+        ComplexNumber::n(1., 0.),
+        ComplexNumber::n(0., 1.),
+    ])?;
+    let cb = c.mul_vec(&x)?;
+    let (gx, _) =
+        gmres(&c, &cb, None, &Identity, 2, &options)?;
+    let (bx, _) =
+        bicgstab(&c, &cb, None, &Identity, &options)?;
+    println!(
+        "{} {}",
+        gx.sub(&x)?.norm() < 1e-10,
+        bx.sub(&x)?.norm() < 1e-10
+    );
+    println!("// true true");
+    match gmres(&a, &b, None, &Identity, 0, &options) {
+        Ok((x, _)) => println!("{}", x),
+        Err(e) => println!("{}", e),
+    }
+    println!("// GMRES restart: Nonconforming dimentions");
+    Ok(())
+}
+
+// Matrix Market files, written to and read from memory
+pub fn matrix_market_test() -> Result<(), LinAlgError> {
+    let a = Matrix::new(vec![1., 0., 0.5, 2.], 2, 2)?;
+    let mut buffer = Vec::new();
+    write_mtx(&mut buffer, &a)?;
+    print!("{}", String::from_utf8_lossy(&buffer));
+    println!("// %%MatrixMarket matrix array real general");
+    println!("// 2 2");
+    println!("// 1e0");
+    println!("// 0e0");
+    println!("// 5e-1");
+    println!("// 2e0");
+    println!(
+        "{}",
+        read_mtx::<f64, _>(buffer.as_slice())?
+            .to_matrix()?
+            == a
+    );
+    println!("// true");
+
+    let sparse = CsrMatrix::from_matrix(&a)?;
+    let mut buffer = Vec::new();
+    write_mtx_sparse(&mut buffer, &sparse)?;
+    print!("{}", String::from_utf8_lossy(&buffer));
+    println!(
+        "// %%MatrixMarket matrix coordinate real general"
+    );
+    println!("// 2 2 3");
+    println!("// 1 1 1e0");
+    println!("// 1 2 5e-1");
+    println!("// 2 2 2e0");
+    println!(
+        "{}",
+        read_mtx::<f64, _>(buffer.as_slice())?.to_csr()?
+            == sparse
+    );
+    println!("// true");
+
+    // only the lower triangle of a symmetric file is stored
+    let symmetric =
+        "%%MatrixMarket matrix coordinate integer symmetric
+% a comment
+3 3 4
+1 1 2
+2 1 -1
+2 2 2
+3 3 2
+";
+    println!(
+        "{}",
+        read_mtx::<f64, _>(symmetric.as_bytes())?
+            .to_matrix()?
+    );
+    println!("// [[2,-1,0],[-1,2,0],[0,0,2]]");
+    let hermitian =
+        "%%MatrixMarket matrix array complex hermitian
+2 2
+1 0
+0 1
+3 0
+";
+    println!(
+        "{}",
+        read_mtx::<ComplexNumber, _>(hermitian.as_bytes())?
+            .to_matrix()?
+    );
+    println!("// [[1 + 0i,0 + 1i],[0 + -1i,3 + 0i]]");
+
+    let broken =
+        "%%MatrixMarket matrix coordinate real general
+2 2 2
+1 1 1.0
+3 1 1.0
+";
+    match read_mtx::<f64, _>(broken.as_bytes()) {
+        Ok(b) => println!("{}", b.len()),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Parse error on line 4: index out of bounds"
+    );
+    match read_mtx::<f64, _>(hermitian.as_bytes()) {
+        Ok(b) => println!("{}", b.len()),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Parse error on line 3: complex entries need a complex matrix"
+    );
+    let skew =
+        "%%MatrixMarket matrix array real skew-symmetric
+3 3
+1
+2
+3
+";
+    println!(
+        "{}",
+        read_mtx::<f64, _>(skew.as_bytes())?.to_matrix()?
+    );
+    println!("// [[0,1,2],[-1,0,3],[-2,-3,0]]");
+
+    // sizes announced by the header are checked against the entries, not
+    // allocated up front
+    for oversized in [
+        "%%MatrixMarket matrix coordinate real general
+2 2 99999999999999999
+1 1 1.0
+",
+        "%%MatrixMarket matrix array real general
+4000000000 4000000000
+1.0
+",
+        "%%MatrixMarket matrix array real general
+4294967296 4294967296
+",
+    ] {
+        match read_mtx::<f64, _>(oversized.as_bytes()) {
+            Ok(b) => println!("{}", b.len()),
+            Err(e) => println!("{}", e),
+        }
+    }
+    println!("// Parse error on line 4: expected 99999999999999999 entries got 1");
+    println!("// Parse error on line 4: expected 16000000000000000000 entries got 1");
+    println!("// Parse error on line 2: matrix too large");
+    Ok(())
+}
+
+// CSV with a header row and missing values, as exported by a spreadsheet
+pub fn csv_test() -> Result<(), LinAlgError> {
+    let data = "height;weight;\"age, years\"
+1.80;80;30
+1.65;NA;25
+1.72;68;41
+";
+    let options = CsvOptions {
+        delimiter: ';',
+        has_header: true,
+        missing: MissingValues::SkipRow,
+    };
+    let (u, names) =
+        Matrix::<f64>::from_csv(data.as_bytes(), &options)?;
+    println!("{:?} {}", names, u);
+    println!(
+        "// Some([\"height\", \"weight\", \"age, years\"]) [[1.8,1.72],[80,68],[30,41]]"
+    );
+    let filled = CsvOptions {
+        missing: MissingValues::Fill(f64::NAN),
+        ..options
+    };
+    let (u, names) =
+        Matrix::<f64>::from_csv(data.as_bytes(), &filled)?;
+    println!("{}", u);
+    println!("// [[1.8,1.65,1.72],[80,NaN,68],[30,25,41]]");
+
+    let mut buffer = Vec::new();
+    u.to_csv(
+        &mut buffer,
+        &CsvOptions::default(),
+        names.as_deref(),
+    )?;
+    print!("{}", String::from_utf8_lossy(&buffer));
+    println!("// height,weight,\"age, years\"");
+    println!("// 1.8,80,30");
+    println!("// 1.65,NaN,25");
+    println!("// 1.72,68,41");
+    let (back, _) = Matrix::<f64>::from_csv(
+        buffer.as_slice(),
+        &CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        },
+    )?;
+    println!("{}", back.el(2, 2)?.is_nan());
+    println!("// true");
+
+    match Matrix::<f64>::from_csv(
+        data.as_bytes(),
+        &CsvOptions {
+            delimiter: ';',
+            has_header: true,
+            missing: MissingValues::Error,
+        },
+    ) {
+        Ok((u, _)) => println!("{}", u),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Parse error on line 3: column 2: missing value"
+    );
+    match Matrix::<f64>::from_csv(
+        "1,2\n3,x\n".as_bytes(),
+        &CsvOptions::default(),
+    ) {
+        Ok((u, _)) => println!("{}", u),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Parse error on line 2: column 2: invalid number \"x\""
+    );
+
+    // a quoted name may span lines
+    let names =
+        vec!["mass\n(kg)".to_string(), "x".to_string()];
+    let m = Matrix::new(vec![1., 2., 3., 4.], 2, 2)?;
+    let mut buffer = Vec::new();
+    m.to_csv(
+        &mut buffer,
+        &CsvOptions::default(),
+        Some(&names),
+    )?;
+    let (back, back_names) = Matrix::<f64>::from_csv(
+        buffer.as_slice(),
+        &CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        },
+    )?;
+    println!("{:?} {}", back_names, back == m);
+    println!("// Some([\"mass\\n(kg)\", \"x\"]) true");
+    match Matrix::<f64>::from_csv(
+        "1,2\n\"3,4\n".as_bytes(),
+        &CsvOptions::default(),
+    ) {
+        Ok((u, _)) => println!("{}", u),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Parse error on line 2: unterminated quoted field");
+    Ok(())
+}
+
+// NumPy .npy and .npz round trips, in memory
+pub fn npy_test() -> Result<(), LinAlgError> {
+    let a =
+        Matrix::new(vec![1., 2., 3., 4., 5., 6.], 2, 3)?;
+    let mut buffer = Vec::new();
+    write_npy(&mut buffer, &a)?;
+    println!(
+        "{} {}",
+        buffer.len(),
+        String::from_utf8_lossy(&buffer[10..128])
+            .trim_end()
+    );
+    println!("// 176 {{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }}");
+    println!(
+        "{}",
+        read_npy::<f64, _>(buffer.as_slice())? == a
+    );
+    println!("// true");
+
+    // the same matrix as numpy.save writes it by default, row by row
+    let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }";
+    let mut c_order = b"\x93NUMPY\x01\x00".to_vec();
+    c_order.extend_from_slice(&118u16.to_le_bytes());
+    c_order.extend_from_slice(header.as_bytes());
+    c_order.extend_from_slice(&[b' '; 58]);
+    c_order.push(b'\n');
+    for x in [1., 3., 5., 2., 4., 6.] {
+        c_order.extend_from_slice(&f64::to_le_bytes(x));
+    }
+    println!("{}", read_npy::<f64, _>(c_order.as_slice())?);
+    println!("// [[1,2],[3,4],[5,6]]");
+
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(1., -1.),
+            ComplexNumber::n(0., 2.),
+        ],
+        2,
+        1,
+    )?;
+    let f = Matrix::new(vec![0.5f32, 1.5], 1, 2)?;
+    let mut buffer = Vec::new();
+    write_npy(&mut buffer, &c)?;
+    println!(
+        "{}",
+        read_npy::<ComplexNumber, _>(buffer.as_slice())?
+    );
+    println!("// [[1 + -1i,0 + 2i]]");
+    match read_npy::<f32, _>(buffer.as_slice()) {
+        Ok(x) => println!("{}", x),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Parse error on line 1: expected dtype f4 got c16");
+
+    let mut archive = Vec::new();
+    write_npz(&mut archive, &[("a", &a), ("b", &a.tr()?)])?;
+    for (name, x) in read_npz::<f64, _>(archive.as_slice())?
+    {
+        println!("{} {}", name, x);
+    }
+    println!("// a [[1,2],[3,4],[5,6]]");
+    println!("// b [[1,3,5],[2,4,6]]");
+    let mut archive = Vec::new();
+    write_npz(&mut archive, &[("f", &f)])?;
+    println!(
+        "{:?}",
+        read_npz::<f32, _>(archive.as_slice())?[0]
+            .1
+            .elements
+    );
+    println!("// [0.5, 1.5]");
+    // flip a byte of the data
+    let flipped = archive.len() - 120;
+    archive[flipped] ^= 1;
+    match read_npz::<f32, _>(archive.as_slice()) {
+        Ok(x) => println!("{}", x.len()),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Parse error on line 1: npz: checksum mismatch in f.npy"
+    );
+    // a name too long for the zip header's 16-bit length field
+    let long = "x".repeat(70000);
+    match write_npz(&mut Vec::new(), &[(&long, &f)]) {
+        Ok(()) => println!("written"),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Parse error on line 1: npz: name too large for a zip archive"
+    );
+
+    // headers claiming more data than the file holds
+    for shape in ["(2, 3)", "(4611686018427387904, 4)"] {
+        let header = format!(
+            "{{'descr': '<f8', 'fortran_order': True, 'shape': {}, }}\n",
+            shape
+        );
+        let mut short = b"\x93NUMPY\x01\x00".to_vec();
+        short.extend_from_slice(
+            &(header.len() as u16).to_le_bytes(),
+        );
+        short.extend_from_slice(header.as_bytes());
+        short.extend_from_slice(&f64::to_le_bytes(1.));
+        match read_npy::<f64, _>(short.as_slice()) {
+            Ok(x) => println!("{}", x),
+            Err(e) => println!("{}", e),
+        }
+    }
+    println!(
+        "// Parse error on line 1: expected 48 bytes of data got 8"
+    );
+    println!("// Parse error on line 1: shape (4611686018427387904, 4) is too large");
+    Ok(())
+}
+
+// Reading back what Display prints
+pub fn parse_test() -> Result<(), LinAlgError> {
+    let a = Matrix::new(vec![1., -2.5, 0.1, 1e-20], 2, 2)?;
+    let text = a.to_string();
+    let back: Matrix<f64> = text.parse()?;
+    println!("{} {}", text, back == a);
+    println!(
+        "// [[1,-2.5],[0.1,0.00000000000000000001]] true"
+    );
+    let v: Vector<f32> = "[0.5,-1,3]".parse()?;
+    println!("{}", v);
+    println!("// [0.5,-1,3]");
+
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(1., -1.),
+            ComplexNumber::Infinity,
+            ComplexNumber::n(0., 0.25),
+            ComplexNumber::n(-3., 0.),
+        ],
+        2,
+        2,
+    )?;
+    let back: Matrix<ComplexNumber> =
+        c.to_string().parse()?;
+    println!("{} {}", c, back == c);
+    println!("// [[1 + -1i,Inf],[0 + 0.25i,-3 + 0i]] true");
+    let z: ComplexNumber = "2 + -0.5i".parse()?;
+    println!("{}", z);
+    println!("// 2 + -0.5i");
+
+    for text in ["[[1,2],[3,x]]", "[[1,2],[3]]", "[1,2"] {
+        match text.parse::<Matrix<f64>>() {
+            Ok(x) => println!("{}", x),
+            Err(e) => println!("{}", e),
+        }
+    }
+    println!(
+        "// Parse error on line 1: invalid number at column 11, found \"x\""
+    );
+    println!("// Parse error on line 1: expected a column of 2 entries got 1 at column 8");
+    println!("// Parse error on line 1: expected '[' at column 2, found \"1\"");
+    match "[1,2]]".parse::<Vector<f64>>() {
+        Ok(x) => println!("{}", x),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Parse error on line 1: expected end of input at column 6, found \"]\"");
+    match "[1 + 2i,3 - 4i]".parse::<Vector<ComplexNumber>>()
+    {
+        Ok(x) => println!("{}", x),
+        Err(e) => println!("{}", e),
+    }
+    println!(
+        "// Parse error on line 1: invalid number at column 9, found \"3 - 4i\""
+    );
+    // no columns: 0 x 2 round trips, 3 x 0 can't say how many rows it had
+    let wide = Matrix::<f64>::zero(0, 2)?;
+    let back: Matrix<f64> = wide.to_string().parse()?;
+    println!("{} {:?}", wide, back.size());
+    println!("// [[],[]] (0, 2)");
+    let tall = Matrix::<f64>::zero(3, 0)?;
+    match tall.to_string().parse::<Matrix<f64>>() {
+        Ok(x) => println!("{:?}", x.size()),
+        Err(e) => println!("{}", e),
+    }
+    println!("// Parse error on line 1: expected a column, the row count of a matrix without columns is unknown at column 2, found \"]\"");
+    Ok(())
+}
+
+// Row by row output with aligned columns, and the LaTeX, Markdown and
+// Octave/Julia variants
+pub fn format_test() -> Result<(), LinAlgError> {
+    let a = Matrix::new(vec![1., -20.5, 0.125, 3.], 2, 2)?;
+    println!("{}", a.pretty());
+    println!("// [     1  0.125 ]");
+    println!("// [ -20.5      3 ]");
+    println!("{:.2}", a.pretty());
+    println!("// [   1.00  0.12 ]");
+    println!("// [ -20.50  3.00 ]");
+    println!("{:<7.1}", a.pretty());
+    println!("// [ 1.0      0.1     ]");
+    println!("// [ -20.5    3.0     ]");
+
+    let big = Matrix::new(
+        (1..=144).map(|x| x as f64).collect(),
+        12,
+        12,
+    )?;
+    println!("{}", big.pretty().max_size(4, 4));
+    println!("// [  1  13  …  121  133 ]");
+    println!("// [  2  14  …  122  134 ]");
+    println!("// [  ⋮   ⋮  ⋱    ⋮    ⋮ ]");
+    println!("// [ 11  23  …  131  143 ]");
+    println!("// [ 12  24  …  132  144 ]");
+
+    println!("{}", a.pretty().latex());
+    println!("// \\begin{{bmatrix}}");
+    println!("//       1 & 0.125 \\\\");
+    println!("//   -20.5 &     3");
+    println!("// \\end{{bmatrix}}");
+    println!("{}", a.pretty().markdown());
+    println!("// |     1 |     2 |");
+    println!("// | ----: | ----: |");
+    println!("// |     1 | 0.125 |");
+    println!("// | -20.5 |     3 |");
+
+    let c = Matrix::new(
+        vec![
+            ComplexNumber::n(1., -2.),
+            ComplexNumber::n(0.5, 0.),
+        ],
+        1,
+        2,
+    )?;
+    println!(
+        "{} {}",
+        a.pretty().octave(),
+        c.pretty().octave()
+    );
+    println!("// [1 0.125; -20.5 3] [1+-2i 0.5+0i]");
+    println!("{:.1}", c.pretty().julia());
+    println!("// [1.0+-2.0im 0.5+0.0im]");
+    println!("{:.1}", c.pretty());
+    println!("// [ 1.0 + -2.0i  0.5 + 0.0i ]");
     Ok(())
 }
@@ -32,6 +32,23 @@ impl Tf64 for f32 {
     }
 }
 
+// Define a trait `Ff64` for building a value from an `f64`
+pub trait Ff64 {
+    fn ff64(x: f64) -> Self;
+}
+// Implement the `Ff64` trait for `f64` to return the value unchanged
+impl Ff64 for f64 {
+    fn ff64(x: f64) -> f64 {
+        x
+    }
+}
+// Implement the `Ff64` trait for `f32` narrowing the value
+impl Ff64 for f32 {
+    fn ff64(x: f64) -> f32 {
+        x as f32
+    }
+}
+
 // Define a trait `Norm` for computing the norm of a value
 pub trait Norm {
     fn norm(&self) -> f64;
@@ -74,6 +91,7 @@ impl Conj for f32 {
 }
 
 use crate::complex::ComplexNumber;
+use crate::kernels::Kernels;
 use ::core::ops::{Add, Div, Mul, Neg, Sub};
 
 // Define a trait `Field` that encompasses operations and traits for field elements
@@ -87,13 +105,26 @@ pub trait Field:
     + Default
     + One
     + Tf64
+    + Ff64
     + Norm
     + Conj
     + Neg
     + std::fmt::Display
+    + Send
+    + Sync
+    + Kernels
 {
+    // Machine epsilon of the underlying real type, so convergence tests
+    // stop at the precision the elements can actually hold
+    const EPSILON: f64;
 }
 // Implement the `Field` trait for `f64` 'f32' and ComplexNumber
-impl Field for f64 {}
-impl Field for f32 {}
-impl Field for ComplexNumber {}
+impl Field for f64 {
+    const EPSILON: f64 = f64::EPSILON;
+}
+impl Field for f32 {
+    const EPSILON: f64 = f32::EPSILON as f64;
+}
+impl Field for ComplexNumber {
+    const EPSILON: f64 = f64::EPSILON;
+}
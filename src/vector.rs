@@ -1,6 +1,6 @@
 use crate::errors::LinAlgError;
 use crate::matrix::Matrix;
-use crate::traits::{Field, Tf64};
+use crate::traits::Field;
 use core::fmt;
 
 // Define `Vector` struct to represent vectors with elements of type `K`, where `K` implements the `Field` trait
@@ -21,7 +21,7 @@ where
     pub fn new(
         elements: Vec<K>,
     ) -> Result<Vector<K>, LinAlgError> {
-        if elements.len() == 0 {
+        if elements.is_empty() {
             return Err(LinAlgError::BuildNonconforming {
                 expected: 1,
                 recieved: 0,
@@ -39,20 +39,38 @@ where
     pub fn size(&self) -> usize {
         self.size
     }
+    // Follow the size of the underlying matrix after it was reused
+    pub(crate) fn sync_size(&mut self) {
+        self.size = self.matrix.size().0;
+    }
     // Accessor method to get the underlying matrix representation of the vector
     pub fn matrix(&self) -> &Matrix<K> {
         &self.matrix
     }
-    // Access a specific element of the vector, with bounds checking
-    pub fn el(&self, i: usize) -> Result<K, LinAlgError> {
-        let n = self.size;
-        if i > n {
+    // Position in the underlying storage of the 1-based entry i, with bounds checking
+    pub(crate) fn offset(
+        &self,
+        i: usize,
+    ) -> Result<usize, LinAlgError> {
+        if i == 0 || i > self.size {
             return Err(LinAlgError::OutofBoundsVector {
                 size: self.size,
                 recieved: i,
             });
         }
-        Ok(self.matrix.el(i, 1)?)
+        Ok(i - 1)
+    }
+    // Access a specific element of the vector, with bounds checking
+    pub fn el(&self, i: usize) -> Result<K, LinAlgError> {
+        Ok(self.matrix.elements[self.offset(i)?])
+    }
+    // Borrow the 0-based entry i if it exists
+    pub fn get(&self, i: usize) -> Option<&K> {
+        self.matrix.elements.get(i)
+    }
+    // Mutably borrow the 0-based entry i if it exists
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut K> {
+        self.matrix.elements.get_mut(i)
     }
 
     // Create a zero vector of specified size
@@ -81,10 +99,9 @@ where
                 },
             );
         }
-        let to_return = Vector::<K>::new(
+        Vector::<K>::new(
             self.matrix.add(&other.matrix)?.elements,
-        );
-        to_return
+        )
     }
 
     // Scale the vector by a factor
@@ -117,7 +134,7 @@ where
         u: &[&Vector<K>],
         coefs: &[K],
     ) -> Result<Vector<K>, LinAlgError> {
-        if u.len() == 0 || coefs.len() == 0 {
+        if u.is_empty() || coefs.is_empty() {
             return Err(LinAlgError::EmptyArgs);
         }
         if u.len() != coefs.len() {
@@ -128,7 +145,18 @@ where
         }
         let mut to_return = Vector::<K>::zero(u[0].size)?;
         for (v, s) in u.iter().zip(coefs.iter()) {
-            to_return = to_return.add(&v.scl(*s)?)?;
+            if v.size() != to_return.size() {
+                return Err(
+                    LinAlgError::OperationNonConforming {
+                        operation: "sum".to_string(),
+                    },
+                );
+            }
+            K::axpy(
+                *s,
+                &v.matrix.elements,
+                &mut to_return.matrix.elements,
+            );
         }
         Ok(to_return)
     }
@@ -156,39 +184,26 @@ where
                 recieved: other.size(),
             });
         }
-        if self.size() == 0 {
-            return Ok(K::default());
-        } else {
-            let m1 =
-                self.matrix.adj()?.mlt(&other.matrix)?;
-            Ok(m1.el(1, 1)?)
-        }
+        Ok(K::dot(
+            &self.matrix.elements,
+            &other.matrix.elements,
+        ))
     }
 
     // Compute the Euclidean norm (magnitude) of the vector
     pub fn norm(&self) -> f64 {
-        self.dot(self).unwrap().tf64().powf(0.5)
+        let elements = &self.matrix.elements;
+        K::dot(elements, elements).tf64().sqrt()
     }
 
     // Compute the \(L_1\) norm (sum of absolute values) of the vector
     pub fn norm_1(&self) -> f64 {
-        let mut x = 0.0;
-        for i in &self.matrix.elements {
-            x += i.norm();
-        }
-        x
+        K::asum(&self.matrix.elements)
     }
 
     // Compute the infinity norm (maximum absolute value) of the vector
     pub fn norm_inf(&self) -> f64 {
-        let mut x = 0.0;
-        for i in &self.matrix.elements {
-            let x2 = i.norm().tf64();
-            if x2 > x {
-                x = x2;
-            }
-        }
-        x
+        K::amax(&self.matrix.elements)
     }
 
     // Calculate the cosine of the angle between two vectors
@@ -236,14 +251,8 @@ where
         el: usize,
         val: K,
     ) -> Result<(), LinAlgError> {
-        let m = self.size();
-        if el > m {
-            return Err(LinAlgError::OutofBoundsVector {
-                size: m,
-                recieved: el,
-            });
-        }
-        self.matrix.set(el, 1, val)?;
+        let offset = self.offset(el)?;
+        self.matrix.elements[offset] = val;
         Ok(())
     }
 }
@@ -0,0 +1,605 @@
+// Borrowed, possibly strided, views into the storage of a `Matrix`
+use core::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{
+    decomposition::LuDecomposition, errors::LinAlgError,
+    gemm::gemm, matrix::Matrix, parallel, traits::Field,
+    vector::Vector,
+};
+
+// Define a trait for anything that can be read as a matrix without copying
+pub trait AsMatrixView<K>
+where
+    K: Field,
+{
+    fn view(&self) -> MatrixView<'_, K>;
+}
+
+// Shape and position of a view inside a column-major buffer
+#[derive(Clone, Copy)]
+struct Layout {
+    offset: usize,
+    size: (usize, usize),
+    strides: (usize, usize),
+}
+
+impl Layout {
+    // Position of the 1-based entry (i, j), with bounds checking
+    fn index(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Result<usize, LinAlgError> {
+        let (n, m) = self.size;
+        if i == 0 || j == 0 || i > n || j > m {
+            return Err(LinAlgError::OutofBoundsMatrix {
+                size: self.size,
+                recieved: (i, j),
+            });
+        }
+        Ok(self.offset
+            + (i - 1) * self.strides.0
+            + (j - 1) * self.strides.1)
+    }
+
+    fn transpose(&self) -> Layout {
+        Layout {
+            offset: self.offset,
+            size: (self.size.1, self.size.0),
+            strides: (self.strides.1, self.strides.0),
+        }
+    }
+
+    // Block made of the 1-based rows and columns in the ranges
+    fn block(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> Result<Layout, LinAlgError> {
+        let (n, m) = self.size;
+        let (r0, r1) = bounds(rows, n);
+        let (c0, c1) = bounds(cols, m);
+        if r0 == 0 || c0 == 0 {
+            return Err(LinAlgError::OutofBoundsMatrix {
+                size: self.size,
+                recieved: (r0, c0),
+            });
+        }
+        if r1 > n || c1 > m || r0 > r1 + 1 || c0 > c1 + 1 {
+            return Err(LinAlgError::OutofBoundsMatrix {
+                size: self.size,
+                recieved: (r1, c1),
+            });
+        }
+        Ok(Layout {
+            offset: self.offset
+                + (r0 - 1) * self.strides.0
+                + (c0 - 1) * self.strides.1,
+            size: (r1 + 1 - r0, c1 + 1 - c0),
+            strides: self.strides,
+        })
+    }
+
+    fn diagonal(&self) -> Layout {
+        let (n, m) = self.size;
+        Layout {
+            offset: self.offset,
+            size: (usize::min(n, m), 1),
+            strides: (self.strides.0 + self.strides.1, 0),
+        }
+    }
+}
+
+// First and last 1-based index of a range over 1..=n
+fn bounds(
+    range: impl RangeBounds<usize>,
+    n: usize,
+) -> (usize, usize) {
+    let first = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 1,
+    };
+    let last = match range.end_bound() {
+        Bound::Included(&e) => e,
+        Bound::Excluded(&e) => e.saturating_sub(1),
+        Bound::Unbounded => n,
+    };
+    (first, last)
+}
+
+// Read-only view with arbitrary row and column strides
+#[derive(Clone, Copy)]
+pub struct MatrixView<'a, K>
+where
+    K: Field,
+{
+    data: &'a [K],
+    layout: Layout,
+}
+
+// Mutable view with arbitrary row and column strides
+pub struct MatrixViewMut<'a, K>
+where
+    K: Field,
+{
+    data: &'a mut [K],
+    layout: Layout,
+}
+
+impl<K> Matrix<K>
+where
+    K: Field,
+{
+    fn layout(&self) -> Layout {
+        let (n, m) = self.size();
+        Layout {
+            offset: 0,
+            size: (n, m),
+            strides: (1, n),
+        }
+    }
+    // View of the whole matrix
+    pub fn view(&self) -> MatrixView<'_, K> {
+        MatrixView {
+            data: &self.elements,
+            layout: self.layout(),
+        }
+    }
+    // Mutable view of the whole matrix
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, K> {
+        let layout = self.layout();
+        MatrixViewMut {
+            data: &mut self.elements,
+            layout,
+        }
+    }
+    // View of the i-th row as a 1 x m matrix
+    pub fn row(
+        &self,
+        i: usize,
+    ) -> Result<MatrixView<'_, K>, LinAlgError> {
+        self.view().submatrix(i..=i, ..)
+    }
+    // View of the j-th column as an n x 1 matrix
+    pub fn col(
+        &self,
+        j: usize,
+    ) -> Result<MatrixView<'_, K>, LinAlgError> {
+        self.view().submatrix(.., j..=j)
+    }
+    // View of the block spanned by 1-based row and column ranges
+    pub fn submatrix(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> Result<MatrixView<'_, K>, LinAlgError> {
+        self.view().submatrix(rows, cols)
+    }
+    // View of the main diagonal as a column
+    pub fn diagonal(&self) -> MatrixView<'_, K> {
+        self.view().diagonal()
+    }
+    // Transposed view, no data is moved
+    pub fn t(&self) -> MatrixView<'_, K> {
+        self.view().t()
+    }
+    // Mutable view of the i-th row
+    pub fn row_mut(
+        &mut self,
+        i: usize,
+    ) -> Result<MatrixViewMut<'_, K>, LinAlgError> {
+        self.submatrix_mut(i..=i, ..)
+    }
+    // Mutable view of the j-th column
+    pub fn col_mut(
+        &mut self,
+        j: usize,
+    ) -> Result<MatrixViewMut<'_, K>, LinAlgError> {
+        self.submatrix_mut(.., j..=j)
+    }
+    // Mutable view of the block spanned by 1-based row and column ranges
+    pub fn submatrix_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> Result<MatrixViewMut<'_, K>, LinAlgError> {
+        let layout = self.layout().block(rows, cols)?;
+        Ok(MatrixViewMut {
+            data: &mut self.elements,
+            layout,
+        })
+    }
+    // Mutable view of the main diagonal
+    pub fn diagonal_mut(&mut self) -> MatrixViewMut<'_, K> {
+        let layout = self.layout().diagonal();
+        MatrixViewMut {
+            data: &mut self.elements,
+            layout,
+        }
+    }
+}
+
+impl<K> AsMatrixView<K> for Matrix<K>
+where
+    K: Field,
+{
+    fn view(&self) -> MatrixView<'_, K> {
+        Matrix::view(self)
+    }
+}
+
+impl<K> AsMatrixView<K> for Vector<K>
+where
+    K: Field,
+{
+    fn view(&self) -> MatrixView<'_, K> {
+        self.matrix.view()
+    }
+}
+
+impl<K> AsMatrixView<K> for MatrixView<'_, K>
+where
+    K: Field,
+{
+    fn view(&self) -> MatrixView<'_, K> {
+        *self
+    }
+}
+
+impl<K> AsMatrixView<K> for MatrixViewMut<'_, K>
+where
+    K: Field,
+{
+    fn view(&self) -> MatrixView<'_, K> {
+        MatrixView {
+            data: self.data,
+            layout: self.layout,
+        }
+    }
+}
+
+impl<'a, K> MatrixView<'a, K>
+where
+    K: Field,
+{
+    // Retrieve the size of the view
+    pub fn size(&self) -> (usize, usize) {
+        self.layout.size
+    }
+    // Access a specific element of the view, with bounds checking
+    pub fn el(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Result<K, LinAlgError> {
+        Ok(self.data[self.layout.index(i, j)?])
+    }
+    // Transposed view
+    pub fn t(&self) -> MatrixView<'a, K> {
+        MatrixView {
+            data: self.data,
+            layout: self.layout.transpose(),
+        }
+    }
+    // View of the i-th row
+    pub fn row(
+        &self,
+        i: usize,
+    ) -> Result<MatrixView<'a, K>, LinAlgError> {
+        self.submatrix(i..=i, ..)
+    }
+    // View of the j-th column
+    pub fn col(
+        &self,
+        j: usize,
+    ) -> Result<MatrixView<'a, K>, LinAlgError> {
+        self.submatrix(.., j..=j)
+    }
+    // View of the block spanned by 1-based row and column ranges
+    pub fn submatrix(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> Result<MatrixView<'a, K>, LinAlgError> {
+        Ok(MatrixView {
+            data: self.data,
+            layout: self.layout.block(rows, cols)?,
+        })
+    }
+    // View of the main diagonal as a column
+    pub fn diagonal(&self) -> MatrixView<'a, K> {
+        MatrixView {
+            data: self.data,
+            layout: self.layout.diagonal(),
+        }
+    }
+    // Copy the entries in column-major order
+    pub fn to_vec(&self) -> Vec<K> {
+        let (n, m) = self.size();
+        let mut to_return = Vec::with_capacity(n * m);
+        for j in 1..=m {
+            for i in 1..=n {
+                to_return.push(
+                    self.data[self.offset_unchecked(i, j)],
+                );
+            }
+        }
+        to_return
+    }
+    // Copy the view into an owned matrix
+    pub fn to_matrix(
+        &self,
+    ) -> Result<Matrix<K>, LinAlgError> {
+        let (n, m) = self.size();
+        Matrix::new(self.to_vec(), n, m)
+    }
+    // Copy a single row or column view into a vector
+    pub fn to_vector(
+        &self,
+    ) -> Result<Vector<K>, LinAlgError> {
+        Vector::new(self.to_vec())
+    }
+
+    // The viewed entries as one slice, when they are stored contiguously
+    // in column-major order
+    pub(crate) fn as_slice(&self) -> Option<&'a [K]> {
+        let (n, m) = self.size();
+        let Layout {
+            offset, strides, ..
+        } = self.layout;
+        if n * m == 0 {
+            return Some(&[]);
+        }
+        if (n == 1 || strides.0 == 1)
+            && (m == 1 || strides.1 == n)
+        {
+            return Some(
+                &self.data[offset..offset + n * m],
+            );
+        }
+        None
+    }
+    // Read the 0-based entry (i, j) without bounds checking
+    pub(crate) fn at(&self, i: usize, j: usize) -> K {
+        self.data[self.layout.offset
+            + i * self.layout.strides.0
+            + j * self.layout.strides.1]
+    }
+
+    fn offset_unchecked(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> usize {
+        self.layout.offset
+            + (i - 1) * self.layout.strides.0
+            + (j - 1) * self.layout.strides.1
+    }
+
+    // Multiply the view with another matrix or view
+    pub fn mlt<M>(
+        &self,
+        other: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        let (n, _) = self.size();
+        let (_, m) = other.view().size();
+        let mut to_return = Matrix::<K>::zero(n, m)?;
+        gemm(
+            K::one(),
+            self,
+            other,
+            K::default(),
+            &mut to_return,
+        )?;
+        Ok(to_return)
+    }
+    // Combine two views of the same size entry by entry into `out`
+    pub(crate) fn zip_with_into<M>(
+        &self,
+        other: &M,
+        operation: &str,
+        f: impl Fn(K, K) -> K + Sync,
+        out: &mut Matrix<K>,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        let other = other.view();
+        if self.size() != other.size() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: operation.to_string(),
+                },
+            );
+        }
+        let (n, m) = self.size();
+        out.reset(n, m);
+        parallel::fill_with(
+            &mut out.elements,
+            n,
+            |i, j| f(self.at(i, j), other.at(i, j)),
+        );
+        Ok(())
+    }
+    fn zip_with<M>(
+        &self,
+        other: &M,
+        operation: &str,
+        f: impl Fn(K, K) -> K + Sync,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        let mut to_return = Matrix::<K>::zero(0, 0)?;
+        self.zip_with_into(
+            other,
+            operation,
+            f,
+            &mut to_return,
+        )?;
+        Ok(to_return)
+    }
+    // Add another matrix or view of the same size
+    pub fn add<M>(
+        &self,
+        other: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.zip_with(other, "Matrix Addition", |x, y| {
+            x + y
+        })
+    }
+    // Subtract another matrix or view of the same size
+    pub fn sub<M>(
+        &self,
+        other: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.zip_with(other, "Matrix Addition", |x, y| {
+            x - y
+        })
+    }
+    // LU factorization of the viewed block
+    pub fn lu(
+        &self,
+    ) -> Result<LuDecomposition<K>, LinAlgError> {
+        self.to_matrix()?.lu()
+    }
+    // Solve A·x = b with the viewed block as A
+    pub fn solve(
+        &self,
+        b: &Vector<K>,
+    ) -> Result<Vector<K>, LinAlgError> {
+        self.to_matrix()?.solve(b)
+    }
+    // Solve A·X = B with the viewed block as A
+    pub fn solve_mat<M>(
+        &self,
+        b: &M,
+    ) -> Result<Matrix<K>, LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        self.to_matrix()?.solve_mat(b)
+    }
+}
+
+impl<'a, K> MatrixViewMut<'a, K>
+where
+    K: Field,
+{
+    // Retrieve the size of the view
+    pub fn size(&self) -> (usize, usize) {
+        self.layout.size
+    }
+    // Access a specific element of the view, with bounds checking
+    pub fn el(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Result<K, LinAlgError> {
+        Ok(self.data[self.layout.index(i, j)?])
+    }
+    // Set a specific element of the view, with bounds checking
+    pub fn set(
+        &mut self,
+        i: usize,
+        j: usize,
+        val: K,
+    ) -> Result<(), LinAlgError> {
+        let index = self.layout.index(i, j)?;
+        self.data[index] = val;
+        Ok(())
+    }
+    // Transposed mutable view
+    pub fn t(self) -> MatrixViewMut<'a, K> {
+        MatrixViewMut {
+            data: self.data,
+            layout: self.layout.transpose(),
+        }
+    }
+    // Narrow the view to a block spanned by 1-based row and column ranges
+    pub fn submatrix(
+        self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> Result<MatrixViewMut<'a, K>, LinAlgError> {
+        Ok(MatrixViewMut {
+            layout: self.layout.block(rows, cols)?,
+            data: self.data,
+        })
+    }
+    // Set every entry of the view to the same value
+    pub fn fill(&mut self, val: K) {
+        let (n, m) = self.size();
+        for j in 1..=m {
+            for i in 1..=n {
+                let index = self.layout.offset
+                    + (i - 1) * self.layout.strides.0
+                    + (j - 1) * self.layout.strides.1;
+                self.data[index] = val;
+            }
+        }
+    }
+    // Overwrite the view with a matrix or view of the same size
+    pub fn copy_from<M>(
+        &mut self,
+        other: &M,
+    ) -> Result<(), LinAlgError>
+    where
+        M: AsMatrixView<K> + ?Sized,
+    {
+        let other = other.view();
+        if self.size() != other.size() {
+            return Err(
+                LinAlgError::OperationNonConforming {
+                    operation: "copy".to_string(),
+                },
+            );
+        }
+        let (n, m) = self.size();
+        for j in 1..=m {
+            for i in 1..=n {
+                self.set(i, j, other.el(i, j)?)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Implement the Display trait for views with the same format as `Matrix`
+impl<K> fmt::Display for MatrixView<'_, K>
+where
+    K: Field,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let (m, n) = self.size();
+        write!(f, "[")?;
+        for j in 1..=n {
+            write!(f, "[")?;
+            for i in 1..=m {
+                write!(f, "{}", self.el(i, j).unwrap())?;
+                if i != m {
+                    write![f, ","]?;
+                }
+            }
+            write!(f, "]")?;
+            if j != n {
+                write!(f, ",")?;
+            }
+        }
+        write!(f, "]")
+    }
+}